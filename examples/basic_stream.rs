@@ -22,19 +22,16 @@ fn build_basic_stream() {
   // we can start from a single byte view
   let mut view = View::new(Calldata::from_hex(command1), WithSig::False);
   view.summary();
-  /* Sig: 
-     Data:  01 
-     View:  ["01"] 
-     Count: 1    */
+  /* 0  0x00  01  */
   // we can append following words
-  [command2, amount, address] . map(|x| view.append(x));
+  [command2, amount, address] . iter() . for_each(|x| view.append(x));
 
   view.summary();
-  /* Sig: 
-     Data:  01ff08e8925e5c2e7de78eea2791bca1f2de4661ed88a30c99a7a9449aa84174 
-     View:  ["01", "ff", "08e8925e5c2e7de78eea", "2791bca1f2de4661ed88a30c99a7a9449aa84174"] 
-     Count: 4
-     Can see we already have our stream above if we just call the data() method
+  /* 0  0x00  01
+     1  0x01  ff
+     2  0x02  08e8925e5c2e7de78eea
+     3  0x0c  2791bca1f2de4661ed88a30c99a7a9449aa84174
+     Index, byte offset and word. Can see we already have our stream above if we just call the data() method
      but for the sake of keeping it simple while exploring functionality let's 
      build it from the elements */
 
@@ -68,15 +65,24 @@ fn build_basic_stream() {
   view.xor_fold_all();
 
   view.summary();
-  /* Sig: 
-     Data:  01ff08e8925e5c2e7de78eea2791bca1f2de4661ed88a30c99a7a9449aa84174 
-     View:  ["01ff08e8925e5c2e7de78eea2791bca1f2de4661ed88a30c99a7a9449aa84174"] 
-     Count: 1  */
+  /* 0  0x00  01ff08e8925e5c2e7de78eea2791bca1f2de4661ed88a30c99a7a9449aa84174  */
   
   // we have essentially concatonated our strings
   let desired = command1.to_owned() + command2 + amount + address;
   assert_eq!(view.data(), desired.to_lowercase());
 
+  // or write it down front to back with a builder. Packed segments take only
+  // their own bytes, and the view it builds has every word noted
+  let built = Calldata::builder()
+    . byte(0x01) . byte(0xff)
+    . uint_packed(42069123456789876543210, 10) . named("amount")
+    . address_packed(address) . named("token")
+    . build_view();
+
+  built.summary();
+  /* 0  0x00  01ff08e8925e5c2e7de78eea2791bca1f2de4661ed88a30c99a7a9449aa84174  // byte, byte, amount, token */
+  assert_eq!(built.data(), view.data());
+
 }
 
 //-----------------------------------------------------------------------------
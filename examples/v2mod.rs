@@ -32,31 +32,38 @@ use kawala::{ View, Calldata, WithSig };
 // ------------------------------------------------------------------------------------------------
 
  /* In this scenario we have a v2 swap that won't go through, and we are going to run a 
-    simulation using tenderly so just want to change a couple of values and grab the hex.        */
+    simulation using tenderly so just want to change a couple of values and grab the hex.
+    Rather than counting slots we bind the router signature, names and all, and let the `View`
+    work out where each argument lives.                                                          */
   
-  const AMOUNT_MIN_SLOT : usize = 1;
-  const TIME_LIMIT_SLOT : usize = 4;
-  const DISTANT_FUTURE  : &str  = "0x04A817C800"; // 20000000000
+  const SWAP_SIG       : &str  = "function swapExactTokensForETHSupportingFeeOnTransferTokens(\
+    uint256 amountIn, uint256 amountOutMin, address[] path, address to, uint256 deadline)";
+  const DISTANT_FUTURE : &str  = "20000000000";
 
  /* Could be they have not accounted for tax on transfer, could be some vesting mechanic.
     Good test is trying the sim with `amountOutMin` at zero just to see if it should go 
     through at all, or if there is something more sinister (perhaps just more inept) to it.      */
+  #[allow(clippy::unused_unit)]
   fn zero_amount_min(view: &mut View) -> () {
-    view.clear(AMOUNT_MIN_SLOT);    // we can `clear()` to replace the word with a [0u8;32]
+    view.clear_named("amountOutMin")  // we can `clear_named()` to replace the word with a [0u8;32]
+      . expect("bound signature has amountOutMin");
   }
 
- /* To be sure we don't smugly rush back proclaiming to have found the simple mistake.           */
+ /* To be sure we don't smugly rush back proclaiming to have found the simple mistake.
+    `set_str` parses the text for the slot's type, and would refuse an address for a uint.       */
+  #[allow(clippy::unused_unit)]
   fn replace_deadline(view : &mut View) -> () {
-    view.replace(TIME_LIMIT_SLOT, DISTANT_FUTURE);
-    view.left_pad(TIME_LIMIT_SLOT);
+    view.set_str("deadline", DISTANT_FUTURE)
+      . expect("bound signature has a uint256 deadline");
   }
   
- /* To grab the before and after, we can use view.word(index) to pull a single word as a String  */
-  fn print(view : &View, id : usize) {
-    println!("[+] => {}", view.word(id));
+ /* To grab the before and after, we can use view.get(name) to pull a decoded value by name.     */
+  fn print(view : &View, name : &str) {
+    println!("[+] => {:<12} {}", name, view.get(name).expect("named argument"));
   }
 
- /* Alternatively, we could use the built in `view.summary()`, for a crude but quick breakdown.    
+ /* Alternatively, we could use the built in `view.summary()`, for a quick breakdown. A bound view
+    notes each parameter beside its head word, `Render` has the column options.                   
   fn quick_sum(view : &View) {
     view.summary();               // prints an overview of the calldata
   } */
@@ -72,17 +79,18 @@ use kawala::{ View, Calldata, WithSig };
 fn main() {
     let string     = "0x791ac94700000000000000000000000000000000000000000000000000000000004c3f88000000000000000000000000000000000000000000000000000ac2d7237640f900000000000000000000000000000000000000000000000000000000000000a000000000000000000000000013a48c3e0a403b6cf1a59fbd600e284e620b37ed0000000000000000000000000000000000000000000000000000000065211d050000000000000000000000000000000000000000000000000000000000000002000000000000000000000000ff970a61a04b1ca14834a43f5de4533ebddb5cc800000000000000000000000082af49447d8a07e3bd95bd0d56f35241523fbab1";
     let mut view   = View::new(Calldata::from_hex(string), WithSig::True);
+    view.bind_sig(SWAP_SIG).expect("valid declaration");
     
-    print!("[>] Before:\n"); 
-    print(&view, AMOUNT_MIN_SLOT);
-    print(&view, TIME_LIMIT_SLOT);  
+    println!("[>] Before:"); 
+    print(&view, "amountOutMin");
+    print(&view, "deadline");  
     
     zero_amount_min(&mut view);
     replace_deadline(&mut view);
-    print!("[>] After:\n");
-    print(&view, AMOUNT_MIN_SLOT);
-    print(&view, TIME_LIMIT_SLOT);  
-    print!("[>] Hex 0x:\n");
+    println!("[>] After:");
+    print(&view, "amountOutMin");
+    print(&view, "deadline");  
+    println!("[>] Hex 0x:");
     hex_0x(&view);
     //quick_sum(&view);
   }
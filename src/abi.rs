@@ -0,0 +1,1068 @@
+/* ----------------------------------------------------------------------------
+   @title  : abi - solidity types, values and the head / tail decoder
+   @author : Maka
+
+   @notice : the word structure a `View` shows us, given meaning. Types parse
+             from their solidity spelling, names and `indexed` markers ride
+             along on `Param`, values decode into a tree that prints readably.
+   errors  : unlike the byte level mods this one has to say why, a short read
+             or a wild offset is an `AbiError` rather than a quiet default.
+// --------------------------------------------------------------------------*/
+
+use crate::prelude::*;
+
+use core::fmt;
+
+/* ----------------------------------------------------------------------------
+ Types
+-----------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamType {
+  Address,
+  Bool,
+  Uint      (usize),
+  Int       (usize),
+  FixedBytes(usize),
+  Bytes,
+  String,
+  Array     (Box<ParamType>),
+  FixedArray(Box<ParamType>, usize),
+  Tuple     (Vec<Param>)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+  pub name     : String,
+  pub kind     : ParamType,
+  pub indexed  : bool,
+  // solidity's own name for the type, `struct Router.Order[]`, json only
+  pub internal : Option<String>
+}
+
+impl ParamType {
+
+  // parse a solidity type, `uint256`, `(address,bytes)[]`, `tuple(..)[2]`
+  pub fn parse(text : &str) -> Result<ParamType, AbiError> {
+    let param = parse_param(text)?;
+    match param.name.is_empty() && !param.indexed {
+      true  => Ok(param.kind),
+      false => Err(AbiError::Parse(text.to_owned()))
+    }
+  }
+  // the canonical spelling used for selectors and topics
+  pub fn canonical(&self) -> String {
+    match self {
+      ParamType::Address          => "address".to_owned(),
+      ParamType::Bool             => "bool".to_owned(),
+      ParamType::Uint(n)          => format!("uint{}", n),
+      ParamType::Int(n)           => format!("int{}", n),
+      ParamType::FixedBytes(n)    => format!("bytes{}", n),
+      ParamType::Bytes            => "bytes".to_owned(),
+      ParamType::String           => "string".to_owned(),
+      ParamType::Array(t)         => format!("{}[]", t.canonical()),
+      ParamType::FixedArray(t, n) => format!("{}[{}]", t.canonical(), n),
+      ParamType::Tuple(ps)        => format!("({})", canonical_list(ps))
+    }
+  }
+  // dynamic types live in the tail and leave an offset in the head
+  pub fn is_dynamic(&self) -> bool {
+    match self {
+      ParamType::Bytes | ParamType::String | ParamType::Array(_) => true,
+      ParamType::FixedArray(t, _) => t.is_dynamic(),
+      ParamType::Tuple(ps)        => ps . iter() . any(|p| p.kind.is_dynamic()),
+      _                           => false
+    }
+  }
+  // bytes taken in the head, an offset word for anything dynamic. Saturates,
+  // `parse` refuses a type whose head can't be counted
+  pub fn head_len(&self) -> usize { self.try_head_len() . unwrap_or(usize::MAX) }
+  // as above, none if the head, or a dynamic type's own head in its tail,
+  // is too big to count
+  pub fn try_head_len(&self) -> Option<usize> {
+    let own = match self {
+      ParamType::FixedArray(t, n) => t.try_head_len()? . checked_mul(*n)?,
+      ParamType::Tuple(ps)        => ps . iter() . try_fold(0usize, |sum, p| sum.checked_add(p.kind.try_head_len()?))?,
+      _                           => WORD
+    };
+    match self.is_dynamic() { true => Some(WORD), false => Some(own) }
+  }
+}
+
+impl Param {
+  pub fn new(name : &str, kind : ParamType) -> Self {
+    Param { name : name.to_owned(), kind, indexed : false, internal : None }
+  }
+  // parse `type [indexed] [name]`
+  pub fn parse(text : &str) -> Result<Param, AbiError> { parse_param(text) }
+}
+
+// parse a comma separated parameter list, names and markers optional
+pub fn parse_params(text : &str) -> Result<Vec<Param>, AbiError> {
+  split_top(text)? . iter() . map(|p| parse_param(p)) . collect()
+}
+
+// canonical list spelling, `address,uint256`
+pub fn canonical_list(params : &[Param]) -> String {
+  params . iter() . map(|p| p.kind.canonical()) . collect::<Vec<String>>() . join(",")
+}
+
+// canonical signature of a declaration, what selectors are hashed from.
+// `transfer(address to, uint amount)` is `transfer(address,uint256)`
+pub fn canonical_signature(text : &str) -> Result<String, AbiError> {
+  Function::parse(text) . map(|f| f.signature())
+}
+
+// split `Name(params) rest` into its three parts
+pub fn split_signature(text : &str) -> Result<(&str, &str, &str), AbiError> {
+  let err  = || AbiError::Parse(text.to_owned());
+  let open = text.find('(') . ok_or_else(err)?;
+  let close = matching(text, open) . ok_or_else(err)?;
+  let name = text[..open].trim();
+  match name . chars() . all(|c| c.is_alphanumeric() || c == '_' || c == '$') {
+    true  => Ok((name, &text[open + 1..close], text[close + 1..].trim())),
+    false => Err(err())
+  }
+}
+
+/* ----------------------------------------------------------------------------
+ Events
+-----------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+  pub name      : String,
+  pub inputs    : Vec<Param>,
+  pub anonymous : bool
+}
+
+impl Event {
+  // parse `Transfer(address indexed from, address indexed to, uint256 value)`,
+  // a trailing `anonymous` marks an event without a topic0
+  pub fn parse(text : &str) -> Result<Event, AbiError> {
+    let body = text.trim(); let body = body.strip_prefix("event ") . unwrap_or(body);
+    let (name, params, rest) = split_signature(body)?;
+    let anonymous = match rest {
+      ""          => false,
+      "anonymous" => true,
+      _           => return Err(AbiError::Parse(text.to_owned()))
+    };
+    Ok(Event { name : name.to_owned(), inputs : parse_params(params)?, anonymous })
+  }
+  // canonical text signature, `Transfer(address,address,uint256)`
+  pub fn signature(&self) -> String {
+    format!("{}({})", self.name, canonical_list(&self.inputs))
+  }
+  // keccak of the signature, the first topic of a non anonymous log
+  pub fn topic0(&self) -> [u8;32] {
+    keccak256(self.signature().as_bytes())
+  }
+}
+
+/* ----------------------------------------------------------------------------
+ Custom errors
+-----------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomError {
+  pub name   : String,
+  pub inputs : Vec<Param>
+}
+
+impl CustomError {
+  // parse `InsufficientBalance(uint256 available, uint256 required)`
+  pub fn parse(text : &str) -> Result<CustomError, AbiError> {
+    let body = text.trim(); let body = body.strip_prefix("error ") . unwrap_or(body);
+    match split_signature(body)? {
+      (name, params, "") => Ok(CustomError { name : name.to_owned(), inputs : parse_params(params)? }),
+      _                  => Err(AbiError::Parse(text.to_owned()))
+    }
+  }
+
+  pub fn signature(&self) -> String {
+    format!("{}({})", self.name, canonical_list(&self.inputs))
+  }
+
+  pub fn selector(&self) -> [u8;4] { selector(&self.signature()) }
+
+  // decode revert data, selector included
+  pub fn decode(&self, data : &[u8]) -> Result<Decoded, AbiError> {
+    match data.len() >= 4 && data[..4] == self.selector() {
+      true  => Ok(Decoded { name : self.name.clone(), fields : decode_params(&self.inputs, &data[4..])? }),
+      false => Err(AbiError::Mismatch(format!("data is not {}", self.signature())))
+    }
+  }
+}
+
+/* ----------------------------------------------------------------------------
+ Functions and constructor
+-----------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Function {
+  pub name       : String,
+  pub inputs     : Vec<Param>,
+  pub outputs    : Vec<Param>,
+  // `pure`, `view`, `nonpayable` or `payable`
+  pub mutability : String
+}
+
+impl Function {
+
+  // parse a human readable declaration, the `function` keyword optional.
+  // `function swap(uint256 amountIn, address[] path) external returns (uint256[])`
+  pub fn parse(text : &str) -> Result<Function, AbiError> {
+    let text = text.trim(); let err = || AbiError::Parse(text.to_owned());
+    let body = text.strip_prefix("function ") . unwrap_or(text);
+    let (name, params, rest) = split_signature(body)?;
+    if name.is_empty() { return Err(err()) }
+    let (modifiers, outputs) = match rest.find("returns") {
+      Some(i) => {
+        let out = rest[i + 7..].trim();
+        match out.starts_with('(') && matching(out, 0) == Some(out.len() - 1) {
+          true  => (&rest[..i], parse_params(&out[1..out.len() - 1])?),
+          false => return Err(err())
+        }
+      },
+      None    => (rest, Vec::new())
+    };
+    Ok(Function {
+      name : name.to_owned(), inputs : parse_params(params)?, outputs,
+      mutability : mutability(modifiers) . ok_or_else(err)?
+    })
+  }
+
+  pub fn signature(&self) -> String {
+    format!("{}({})", self.name, canonical_list(&self.inputs))
+  }
+
+  pub fn selector(&self) -> [u8;4] { selector(&self.signature()) }
+
+  // decode calldata, selector included
+  pub fn decode_input(&self, data : &[u8]) -> Result<Decoded, AbiError> {
+    match data.len() >= 4 && data[..4] == self.selector() {
+      true  => Ok(Decoded { name : self.name.clone(), fields : decode_params(&self.inputs, &data[4..])? }),
+      false => Err(AbiError::Mismatch(format!("calldata is not {}", self.signature())))
+    }
+  }
+  // decode return data against the outputs
+  pub fn decode_output(&self, data : &[u8]) -> Result<Decoded, AbiError> {
+    Ok(Decoded { name : self.name.clone(), fields : decode_params(&self.outputs, data)? })
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Constructor {
+  pub inputs     : Vec<Param>,
+  pub mutability : String
+}
+
+impl Constructor {
+  // parse `constructor(address factory, address weth) payable`
+  pub fn parse(text : &str) -> Result<Constructor, AbiError> {
+    let err = || AbiError::Parse(text.to_owned());
+    match split_signature(text.trim())? {
+      ("constructor", params, rest) => Ok(Constructor {
+        inputs : parse_params(params)?, mutability : mutability(rest) . ok_or_else(err)?
+      }),
+      _ => Err(err())
+    }
+  }
+  // decode the arguments appended to creation code, code not included
+  pub fn decode(&self, args : &[u8]) -> Result<Decoded, AbiError> {
+    Ok(Decoded { name : "constructor".to_owned(), fields : decode_params(&self.inputs, args)? })
+  }
+}
+
+/* ----------------------------------------------------------------------------
+ Abi, a whole interface
+-----------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Abi {
+  pub functions   : Vec<Function>,
+  pub events      : Vec<Event>,
+  pub errors      : Vec<CustomError>,
+  pub constructor : Option<Constructor>
+}
+
+impl Abi {
+
+  // parse a solidity json abi, the bare array or an artifact holding it
+  // under `abi` as solc and foundry write them. Fallback and receive have
+  // no inputs to decode so they are skipped
+  pub fn from_json(text : &str) -> Result<Abi, AbiError> {
+    let mut abi = Abi::default();
+    json_entries(text)? . iter() . try_for_each(|entry| abi._json_entry(entry))?;
+    Ok(abi)
+  }
+  // as above, entries that can't be read are left out instead of failing
+  // the lot. Returns how many were left out
+  pub fn from_json_lossy(text : &str) -> Result<(Abi, usize), AbiError> {
+    let mut abi = Abi::default();
+    let skipped = json_entries(text)? . iter() . filter(|entry| abi._json_entry(entry).is_err()) . count();
+    Ok((abi, skipped))
+  }
+
+  // *private* one entry of a json abi, added only if all of it reads
+  fn _json_entry(&mut self, entry : &Json) -> Result<(), AbiError> {
+    let err = |s : &str| AbiError::Parse(s.to_owned());
+    let kind = entry . get("type") . and_then(|t| t.as_str()) . unwrap_or("function");
+    let name = || entry . get("name") . and_then(|n| n.as_str()) . map(str::to_owned) . ok_or_else(|| err("name"));
+    let list = |key| json_params(entry . get(key));
+    let mutability = entry . get("stateMutability") . and_then(|m| m.as_str()) . unwrap_or("nonpayable") . to_owned();
+    match kind {
+      "function"    => self.functions . push(Function {
+        name : name()?, inputs : list("inputs")?, outputs : list("outputs")?, mutability
+      }),
+      "event"       => self.events . push(Event {
+        name : name()?, inputs : list("inputs")?,
+        anonymous : entry . get("anonymous") . and_then(|a| a.as_bool()) . unwrap_or(false)
+      }),
+      "error"       => self.errors . push(CustomError { name : name()?, inputs : list("inputs")? }),
+      "constructor" => self.constructor = Some(Constructor { inputs : list("inputs")?, mutability }),
+      "fallback" | "receive" => (),
+      other         => return Err(err(other))
+    }
+    Ok(())
+  }
+
+  // build from human readable declarations, the way ethers takes them.
+  // each is a `function`, `event`, `error` or `constructor`, a bare
+  // `name(..)` is taken as a function
+  pub fn from_human(declarations : &[&str]) -> Result<Abi, AbiError> {
+    let mut abi = Abi::default();
+    for text in declarations {
+      let text = text.trim();
+      match text . split(|c: char| c.is_whitespace() || c == '(') . next() . unwrap_or("") {
+        "event"       => abi.events . push(Event::parse(text)?),
+        "error"       => abi.errors . push(CustomError::parse(text)?),
+        "constructor" => abi.constructor = Some(Constructor::parse(text)?),
+        _             => abi.functions . push(Function::parse(text)?)
+      }
+    }
+    Ok(abi)
+  }
+
+  pub fn function(&self, name : &str) -> Option<&Function> {
+    self.functions . iter() . find(|f| f.name == name)
+  }
+
+  pub fn event(&self, name : &str) -> Option<&Event> {
+    self.events . iter() . find(|e| e.name == name)
+  }
+
+  pub fn error(&self, name : &str) -> Option<&CustomError> {
+    self.errors . iter() . find(|e| e.name == name)
+  }
+}
+
+// the entries of a json abi, the array itself or an artifact's `abi`
+fn json_entries(text : &str) -> Result<Vec<Json>, AbiError> {
+  let err = |s : &str| AbiError::Parse(s.to_owned());
+  let doc = json::parse(text) . ok_or_else(|| err("json"))?;
+  doc . get("abi") . unwrap_or(&doc) . as_array() . map(|a| a.to_vec()) . ok_or_else(|| err("abi array"))
+}
+
+// a json `inputs` or `outputs` list, absent is empty
+fn json_params(list : Option<&Json>) -> Result<Vec<Param>, AbiError> {
+  list . and_then(|l| l.as_array()) . unwrap_or(&[]) . iter() . map(json_param) . collect()
+}
+
+// one json param, tuples build from their components
+fn json_param(entry : &Json) -> Result<Param, AbiError> {
+  let field = |key| entry . get(key) . and_then(|v : &Json| v.as_str());
+  let ty    = field("type") . ok_or_else(|| AbiError::Parse("param type".to_owned()))?;
+  let err   = || AbiError::Parse(ty.to_owned());
+  let (kind, rest) = match ty.strip_prefix("tuple") {
+    Some(suffix) => suffixes(ParamType::Tuple(json_params(entry . get("components"))?), suffix),
+    None         => {
+      let end = ty.find('[') . unwrap_or(ty.len());
+      suffixes(elementary(&ty[..end]) . ok_or_else(err)?, &ty[end..])
+    }
+  } . ok_or_else(err)?;
+  // a fixed array too long to lay out, as in `parse_param`
+  kind.try_head_len() . ok_or_else(err)?;
+  match rest.is_empty() {
+    true  => Ok(Param {
+      name     : field("name") . unwrap_or("") . to_owned(),
+      kind,
+      indexed  : entry . get("indexed") . and_then(|i| i.as_bool()) . unwrap_or(false),
+      internal : field("internalType") . map(str::to_owned)
+    }),
+    false => Err(err())
+  }
+}
+
+/* ----------------------------------------------------------------------------
+ Values
+-----------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+  Address   ([u8;20]),
+  Bool      (bool),
+  Uint      ([u8;32]),
+  Int       ([u8;32]),
+  FixedBytes(Vec<u8>),
+  Bytes     (Vec<u8>),
+  String    (String),
+  Array     (Vec<Value>),
+  FixedArray(Vec<Value>),
+  Tuple     (Vec<Value>),
+  // indexed dynamic values only leave their keccak behind in a topic
+  Hashed    ([u8;32])
+}
+
+impl Value {
+
+  // parse text as a value of the given type. Numbers are decimal or 0x hex,
+  // bytes are hex, strings are taken as is, lists are `[a, b]` or `(a, b)`
+  pub fn parse(kind : &ParamType, text : &str) -> Result<Value, AbiError> {
+    let text = text.trim(); let err = || AbiError::Type(format!("{} can't hold `{}`", kind.canonical(), text));
+    let hex  = |t : &str| -> Option<Vec<u8>> {
+      let h = t.strip_prefix("0x")?;
+      match h.len() % 2 == 0 && h . chars() . all(|c| c.is_ascii_hexdigit()) {
+        true => Some(hex_to_bytes(h)), false => None
+      }
+    };
+    let num  = |t : &str| -> Option<[u8;32]> {
+      match t.starts_with("0x") {
+        true  => hex(t) . filter(|b| b.len() <= 32) . map(|b| pad32l(&b)),
+        false => from_dec32(t)
+      }
+    };
+    let items = |open : char, close : char| -> Result<Vec<&str>, AbiError> {
+      let inner = text.strip_prefix(open) . and_then(|t| t.strip_suffix(close)) . ok_or_else(err)?;
+      split_list(inner) . ok_or_else(err)
+    };
+    let value = match kind {
+      ParamType::Address       => hex(text) . filter(|b| b.len() == 20)
+                                . map(|b| Value::Address(b . try_into() . unwrap_or([0;20]))),
+      ParamType::Bool          => match text { "true" | "1" => Some(Value::Bool(true)), "false" | "0" => Some(Value::Bool(false)), _ => None },
+      ParamType::Uint(_)       => num(text) . map(Value::Uint),
+      // magnitudes up to 2^(bits-1) below zero, short of it above
+      ParamType::Int(bits)     => {
+        let mut limit = [0u8;32]; limit[32 - bits / 8] = 0x80;
+        match text.strip_prefix('-') {
+          Some(t) => num(t) . filter(|m| *m <= limit) . map(|m| Value::Int(neg32(&m))),
+          None    => num(text) . filter(|m| *m < limit) . map(Value::Int)
+        }
+      },
+      ParamType::FixedBytes(n) => hex(text) . filter(|b| b.len() == *n) . map(Value::FixedBytes),
+      ParamType::Bytes         => hex(text) . map(Value::Bytes),
+      ParamType::String        => Some(Value::String(text.to_owned())),
+      ParamType::Array(t)      => Some(Value::Array(
+        items('[', ']')? . iter() . map(|i| Value::parse(t, i)) . collect::<Result<_, _>>()?
+      )),
+      ParamType::FixedArray(t, n) => {
+        let vs = items('[', ']')? . iter() . map(|i| Value::parse(t, i)) . collect::<Result<Vec<_>, _>>()?;
+        match vs.len() == *n { true => Some(Value::FixedArray(vs)), false => None }
+      },
+      ParamType::Tuple(ps)     => {
+        let is = items('(', ')')?;
+        match is.len() == ps.len() {
+          true  => Some(Value::Tuple(
+            ps . iter() . zip(is) . map(|(p, i)| Value::parse(&p.kind, i)) . collect::<Result<_, _>>()?
+          )),
+          false => None
+        }
+      }
+    };
+    value . filter(|v| v.fits(kind)) . ok_or_else(err)
+  }
+
+  // can a slot of this type hold the value, bit widths included
+  pub fn fits(&self, kind : &ParamType) -> bool {
+    let all = |vs : &[Value], t : &ParamType| vs . iter() . all(|v| v.fits(t));
+    match (self, kind) {
+      (Value::Address(_), ParamType::Address)           => true,
+      (Value::Bool(_), ParamType::Bool)                 => true,
+      (Value::Uint(w), ParamType::Uint(bits))           => w[..32 - bits / 8] . iter() . all(|b| *b == 0),
+      (Value::Int(w), ParamType::Int(bits))             => {
+        let fill = if w[32 - bits / 8] & 0x80 != 0 { 0xff } else { 0x00 };
+        w[..32 - bits / 8] . iter() . all(|b| *b == fill)
+      },
+      (Value::FixedBytes(b), ParamType::FixedBytes(n))  => b.len() == *n,
+      (Value::Bytes(_), ParamType::Bytes)               => true,
+      (Value::String(_), ParamType::String)             => true,
+      (Value::Array(vs), ParamType::Array(t))           => all(vs, t),
+      (Value::FixedArray(vs), ParamType::FixedArray(t, n)) => vs.len() == *n && all(vs, t),
+      (Value::Tuple(vs), ParamType::Tuple(ps))          => vs.len() == ps.len()
+        && vs . iter() . zip(ps) . all(|(v, p)| v.fits(&p.kind)),
+      _                                                 => false
+    }
+  }
+
+  // the single head word for a value type, type checked
+  pub fn encode_word(&self, kind : &ParamType) -> Result<[u8;32], AbiError> {
+    if !self.fits(kind) {
+      return Err(AbiError::Type(format!("{} can't hold {}", kind.canonical(), self)))
+    }
+    match self {
+      Value::Address(a)    => Ok(pad32l(a)),
+      Value::Bool(b)       => Ok(pad32l(&[*b as u8])),
+      Value::Uint(w)       |
+      Value::Int(w)        => Ok(*w),
+      Value::FixedBytes(b) => Ok(pad32r(b)),
+      _ => Err(AbiError::Type(format!("{} is not a single word", kind.canonical())))
+    }
+  }
+}
+
+impl fmt::Display for Value {
+  fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+    let list = |f : &mut fmt::Formatter, vs : &[Value]| -> fmt::Result {
+      vs . iter() . enumerate() . try_for_each(|(i, v)| {
+        if i > 0 { write!(f, ", ")?; } write!(f, "{}", v)
+      })
+    };
+    match self {
+      Value::Address(a)    => write!(f, "0x{}", bytes_to_hex(a)),
+      Value::Bool(b)       => write!(f, "{}", b),
+      Value::Uint(w)       => write!(f, "{}", dec32(w)),
+      Value::Int(w)        => match w[0] & 0x80 != 0 {
+        true  => write!(f, "-{}", dec32(&neg32(w))),
+        false => write!(f, "{}", dec32(w))
+      },
+      Value::FixedBytes(b) |
+      Value::Bytes(b)      => write!(f, "0x{}", bytes_to_hex(b)),
+      Value::String(s)     => write!(f, "{:?}", s),
+      Value::Array(vs)     |
+      Value::FixedArray(vs) => { write!(f, "[")?; list(f, vs)?; write!(f, "]") },
+      Value::Tuple(vs)     => { write!(f, "(")?; list(f, vs)?; write!(f, ")") },
+      Value::Hashed(h)     => write!(f, "keccak(0x{})", bytes_to_hex(h))
+    }
+  }
+}
+
+// a decoded call, log or error. Fields keep their parameter names
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decoded {
+  pub name   : String,
+  pub fields : Vec<(String, Value)>
+}
+
+impl Decoded {
+  // value of a named field
+  pub fn get(&self, name : &str) -> Option<&Value> {
+    self.fields . iter() . find(|(n, _)| n == name) . map(|(_, v)| v)
+  }
+  // values in declaration order
+  pub fn values(&self) -> Vec<Value> {
+    self.fields . iter() . map(|(_, v)| v.clone()) . collect()
+  }
+}
+
+impl fmt::Display for Decoded {
+  fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}(", self.name)?;
+    self.fields . iter() . enumerate() . try_for_each(|(i, (n, v))| {
+      if i > 0 { write!(f, ", ")?; }
+      match n.is_empty() { true => write!(f, "{}", v), false => write!(f, "{}: {}", n, v) }
+    })?;
+    write!(f, ")")
+  }
+}
+
+/* ----------------------------------------------------------------------------
+ Decoding
+-----------------------------------------------------------------------------*/
+
+// decode an abi encoded parameter sequence
+pub fn decode(kinds : &[ParamType], data : &[u8]) -> Result<Vec<Value>, AbiError> {
+  decode_seq(kinds . iter(), data, 0)
+}
+
+// decode a parameter list, pairing each value with its name
+pub fn decode_params(params : &[Param], data : &[u8]) -> Result<Vec<(String, Value)>, AbiError> {
+  let values = decode_seq(params . iter() . map(|p| &p.kind), data, 0)?;
+  Ok(params . iter() . map(|p| p.name.clone()) . zip(values) . collect())
+}
+
+// decode a single value whose encoding starts at `at`
+pub fn decode_value(kind : &ParamType, data : &[u8], at : usize) -> Result<Value, AbiError> {
+  match kind {
+    ParamType::Address       => Ok(Value::Address(word(data, at)?[12..] . try_into() . unwrap_or([0;20]))),
+    ParamType::Bool          => Ok(Value::Bool(word(data, at)? . iter() . any(|b| *b != 0))),
+    ParamType::Uint(_)       => Ok(Value::Uint(word(data, at)?)),
+    ParamType::Int(_)        => Ok(Value::Int(word(data, at)?)),
+    ParamType::FixedBytes(n) => Ok(Value::FixedBytes(word(data, at)?[..*n] . to_vec())),
+    ParamType::Bytes         => Ok(Value::Bytes(packed(data, at)? . to_vec())),
+    ParamType::String        => Ok(Value::String(
+      String::from_utf8(packed(data, at)? . to_vec()) . map_err(|_| AbiError::BadValue(at))?
+    )),
+    ParamType::Array(t)      => {
+      let len = usize_at(data, at)?;
+      // every element takes at least a word, a bigger count can't be honest
+      if len > data.len() / WORD { return Err(AbiError::OutOfBounds(at)) }
+      decode_seq(core::iter::repeat_n(&**t, len), data, at + WORD) . map(Value::Array)
+    },
+    ParamType::FixedArray(t, n) => decode_seq(core::iter::repeat_n(&**t, *n), data, at) . map(Value::FixedArray),
+    ParamType::Tuple(ps)     => decode_seq(ps . iter() . map(|p| &p.kind), data, at) . map(Value::Tuple)
+  }
+}
+
+// walk a head from `base`, following offsets relative to it into the tail
+fn decode_seq<'a, I>(kinds : I, data : &[u8], base : usize) -> Result<Vec<Value>, AbiError>
+where I: Iterator<Item = &'a ParamType> {
+  let mut pos = base;
+  kinds . map(|kind| {
+    let value = match kind.is_dynamic() {
+      true  => {
+        let offset = usize_at(data, pos)?;
+        decode_value(kind, data, base . checked_add(offset) . ok_or(AbiError::BadOffset(pos))?)
+      },
+      false => decode_value(kind, data, pos)
+    };
+    pos += kind.head_len(); value
+  })
+  . collect()
+}
+
+// 32 bytes at `at`
+fn word(data : &[u8], at : usize) -> Result<[u8;32], AbiError> {
+  data . get(at..at . saturating_add(WORD))
+  . map(|w| w . try_into() . unwrap_or([0;32]))
+  . ok_or(AbiError::OutOfBounds(at))
+}
+
+// a word read as an offset or length, must fit the data to be believable
+fn usize_at(data : &[u8], at : usize) -> Result<usize, AbiError> {
+  let w = word(data, at)?;
+  match w[..24] . iter() . all(|b| *b == 0) {
+    true  => {
+      let n = u64::from_be_bytes(w[24..] . try_into() . unwrap_or([0;8])) as usize;
+      match n <= data.len() { true => Ok(n), false => Err(AbiError::BadOffset(at)) }
+    },
+    false => Err(AbiError::BadOffset(at))
+  }
+}
+
+// length prefixed bytes at `at`
+fn packed(data : &[u8], at : usize) -> Result<&[u8], AbiError> {
+  let len = usize_at(data, at)?; let start = at + WORD;
+  data . get(start..start + len) . ok_or(AbiError::OutOfBounds(start))
+}
+
+/* ----------------------------------------------------------------------------
+ Encoding
+-----------------------------------------------------------------------------*/
+
+// encode a parameter sequence, the standard head / tail layout
+pub fn encode(kinds : &[ParamType], values : &[Value]) -> Result<Vec<u8>, AbiError> {
+  if kinds.len() != values.len() {
+    return Err(AbiError::Mismatch(format!("{} values for {} types", values.len(), kinds.len())))
+  }
+  encode_seq(kinds, values)
+}
+
+// encode a single value as it sits where its encoding starts, the tail for
+// dynamic types, the head words for anything else
+pub fn encode_value(kind : &ParamType, value : &Value) -> Result<Vec<u8>, AbiError> {
+  let err = || AbiError::Type(format!("{} can't hold {}", kind.canonical(), value));
+  let packed = |bytes : &[u8]| {
+    let mut out = pad32l(&(bytes.len() as u64).to_be_bytes()).to_vec();
+    bytes . chunks(WORD) . for_each(|c| out . extend_from_slice(&pad32r(c)));
+    out
+  };
+  match (kind, value) {
+    (ParamType::Bytes, Value::Bytes(b))     => Ok(packed(b)),
+    (ParamType::String, Value::String(s))   => Ok(packed(s.as_bytes())),
+    (ParamType::Array(t), Value::Array(vs)) => {
+      let kinds = vec![(**t).clone(); vs.len()];
+      Ok([pad32l(&(vs.len() as u64).to_be_bytes()).to_vec(), encode_seq(&kinds, vs)?].concat())
+    },
+    (ParamType::FixedArray(t, n), Value::FixedArray(vs)) if vs.len() == *n =>
+      encode_seq(&vec![(**t).clone(); *n], vs),
+    (ParamType::Tuple(ps), Value::Tuple(vs)) if vs.len() == ps.len() =>
+      encode_seq(&ps . iter() . map(|p| p.kind.clone()) . collect::<Vec<_>>(), vs),
+    (ParamType::Array(_) | ParamType::FixedArray(..) | ParamType::Tuple(_)
+      | ParamType::Bytes | ParamType::String, _) => Err(err()),
+    _ => value.encode_word(kind) . map(|w| w.to_vec())
+  }
+}
+
+// heads in order, each dynamic one an offset to its part of the tail
+fn encode_seq(kinds : &[ParamType], values : &[Value]) -> Result<Vec<u8>, AbiError> {
+  let head_len = kinds . iter() . map(|k| k.head_len()) . sum::<usize>();
+  let mut head = Vec::new(); let mut tail = Vec::new();
+  for (kind, value) in kinds . iter() . zip(values) {
+    let part = encode_value(kind, value)?;
+    match kind.is_dynamic() {
+      true  => {
+        head . extend_from_slice(&pad32l(&((head_len + tail.len()) as u64).to_be_bytes()));
+        tail . extend(part)
+      },
+      false => head . extend(part)
+    }
+  }
+  Ok([head, tail].concat())
+}
+
+/* ----------------------------------------------------------------------------
+ Layout
+-----------------------------------------------------------------------------*/
+
+// find a value by path in encoded params, `order.maker`, `path[1]`,
+// `orders[0].amount`. Returns its type and the byte its encoding starts at,
+// for a value type that is the word holding it
+pub fn locate(params : &[Param], data : &[u8], path : &str) -> Result<(ParamType, usize), AbiError> {
+  let err = || AbiError::Parse(path.to_owned());
+  let mut kind = ParamType::Tuple(params.to_vec()); let mut at = 0;
+  for step in steps(path) . ok_or_else(err)? {
+    let (child, head, base) = match (&kind, step) {
+      (ParamType::Tuple(ps), Step::Field(name)) => {
+        let i = ps . iter() . position(|p| p.name == name) . ok_or_else(err)?;
+        (ps[i].kind.clone(), at + ps[..i] . iter() . map(|p| p.kind.head_len()) . sum::<usize>(), at)
+      },
+      (ParamType::Tuple(ps), Step::Index(i)) if i < ps.len() =>
+        (ps[i].kind.clone(), at + ps[..i] . iter() . map(|p| p.kind.head_len()) . sum::<usize>(), at),
+      (ParamType::Array(t), Step::Index(i)) => {
+        if i >= usize_at(data, at)? { return Err(AbiError::OutOfBounds(at)) }
+        (*t.clone(), at + WORD + i * t.head_len(), at + WORD)
+      },
+      (ParamType::FixedArray(t, n), Step::Index(i)) if i < *n =>
+        (*t.clone(), at + i * t.head_len(), at),
+      _ => return Err(err())
+    };
+    at = match child.is_dynamic() {
+      true  => base . checked_add(usize_at(data, head)?) . ok_or(AbiError::BadOffset(head))?,
+      false => head
+    };
+    kind = child;
+  }
+  Ok((kind, at))
+}
+
+enum Step<'a> { Field(&'a str), Index(usize) }
+
+// `orders[0].amount` => orders, 0, amount. A leading `[n]` indexes the params
+fn steps(path : &str) -> Option<Vec<Step<'_>>> {
+  let mut out = Vec::new();
+  for part in path.split('.') {
+    let end = part.find('[') . unwrap_or(part.len());
+    if !part[..end].is_empty() { out.push(Step::Field(&part[..end])) }
+    else if end == part.len() { return None }
+    let mut rest = &part[end..];
+    while let Some(r) = rest.strip_prefix('[') {
+      let close = r.find(']')?;
+      out.push(Step::Index(r[..close].trim().parse().ok()?)); rest = &r[close + 1..];
+    }
+    if !rest.is_empty() { return None }
+  }
+  Some(out)
+}
+
+/* ----------------------------------------------------------------------------
+ Links, the offset and length words an edit has to keep true
+-----------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Link {
+  // an offset word at `at`, relative to `base`, pointing at `target`
+  Offset { at : usize, base : usize, target : usize },
+  // a length word at `at` counting `count` elements of `unit` bytes. Only
+  // bytes, strings and arrays of one word values, where words are elements
+  Length { at : usize, count : usize, unit : usize }
+}
+
+impl Link {
+  // the byte the link's own word sits at
+  pub fn at(&self) -> usize {
+    match self { Link::Offset { at, .. } | Link::Length { at, .. } => *at }
+  }
+}
+
+// every offset and length word in encoded params, by walking the types
+pub fn links(params : &[Param], data : &[u8]) -> Result<Vec<Link>, AbiError> {
+  let mut out = Vec::new();
+  links_seq(params . iter() . map(|p| &p.kind), data, 0, &mut out)?;
+  Ok(out)
+}
+
+// as above with no types to go on. A head word that is a forward, word
+// aligned offset into the data is taken as one, and what it points at as a
+// length prefixed array of words, bytes, or one level of nested offsets.
+// A heuristic, an amount that happens to look like an offset will fool it
+pub fn infer_links(data : &[u8]) -> Vec<Link> {
+  let mut out = Vec::new(); infer_seq(data, 0, data.len(), &mut out, true); out
+}
+
+// new position and value of each link word after `delta` words are inserted
+// at byte `at`, or removed from it when negative. A target sitting right at
+// an insertion moves with it, and an array ending there grows into it
+pub fn relink(links : &[Link], at : usize, delta : isize) -> Vec<(usize, usize)> {
+  let span = delta.unsigned_abs() * WORD;
+  // where a byte ends up, `inclusive` moves a byte sitting at the edit too
+  let moved = |p : usize, inclusive : bool| match delta >= 0 {
+    true  => match p > at || (inclusive && p == at) { true => p + span, false => p },
+    false => match p { p if p >= at + span => p - span, p if p > at => at, p => p }
+  };
+  links . iter() . filter_map(|link| {
+    let pos = link.at();
+    if delta < 0 && pos >= at && pos < at + span { return None }
+    let value = match *link {
+      Link::Offset { base, target, .. } => moved(target, true) . saturating_sub(moved(base, false)),
+      Link::Length { count, unit, .. } => {
+        let end = pos + WORD + (count * unit) . div_ceil(WORD) * WORD;
+        match delta >= 0 {
+          true  if at > pos && at <= end              => count + span / unit,
+          false if at > pos && at + span <= end       => count . saturating_sub(span / unit),
+          _                                           => count
+        }
+      }
+    };
+    Some((moved(pos, true), value))
+  })
+  . collect()
+}
+
+fn links_seq<'a, I>(kinds : I, data : &[u8], base : usize, out : &mut Vec<Link>) -> Result<(), AbiError>
+where I: Iterator<Item = &'a ParamType> {
+  let mut pos = base;
+  for kind in kinds {
+    if kind.is_dynamic() {
+      let target = base . checked_add(usize_at(data, pos)?) . ok_or(AbiError::BadOffset(pos))?;
+      out.push(Link::Offset { at : pos, base, target });
+      links_value(kind, data, target, out)?;
+    } else {
+      links_value(kind, data, pos, out)?;
+    }
+    pos += kind.head_len();
+  }
+  Ok(())
+}
+
+fn links_value(kind : &ParamType, data : &[u8], at : usize, out : &mut Vec<Link>) -> Result<(), AbiError> {
+  match kind {
+    ParamType::Bytes | ParamType::String => {
+      out.push(Link::Length { at, count : usize_at(data, at)?, unit : 1 }); Ok(())
+    },
+    ParamType::Array(t) => {
+      let count = usize_at(data, at)?;
+      if count > data.len() / WORD { return Err(AbiError::OutOfBounds(at)) }
+      if !t.is_dynamic() && t.head_len() == WORD { out.push(Link::Length { at, count, unit : WORD }) }
+      links_seq(core::iter::repeat_n(&**t, count), data, at + WORD, out)
+    },
+    ParamType::FixedArray(t, n) => links_seq(core::iter::repeat_n(&**t, *n), data, at, out),
+    ParamType::Tuple(ps)        => links_seq(ps . iter() . map(|p| &p.kind), data, at, out),
+    _                           => Ok(())
+  }
+}
+
+// offsets in the head from `base`, the head ends at the first target
+#[allow(clippy::unused_unit)]
+fn infer_seq(data : &[u8], base : usize, end : usize, out : &mut Vec<Link>, nest : bool) -> () {
+  let mut head_end = end; let mut targets = Vec::new(); let mut pos = base;
+  while pos < head_end {
+    let target = usize_at(data, pos) . ok() . map(|v| base + v);
+    if let Some(t) = target . filter(|t| (t - base) . is_multiple_of(WORD) && *t > pos && *t + WORD <= end) {
+      out.push(Link::Offset { at : pos, base, target : t });
+      targets.push(t); head_end = head_end . min(t);
+    }
+    pos += WORD;
+  }
+  targets.sort();
+  targets . iter() . enumerate() . for_each(|(i, t)| {
+    let region = targets . get(i + 1) . copied() . unwrap_or(end) . max(*t) - t;
+    infer_tail(data, *t, region, out, nest)
+  })
+}
+
+// what a target points at, judged by how its length fills the region
+#[allow(clippy::unused_unit)]
+fn infer_tail(data : &[u8], at : usize, region : usize, out : &mut Vec<Link>, nest : bool) -> () {
+  let count = match usize_at(data, at) { Ok(n) => n, Err(_) => return };
+  let start = at + WORD;
+  let offsets = nest && count > 0 && (0..count) . all(|i| {
+    usize_at(data, start + i * WORD) . ok()
+    . is_some_and(|v| v % WORD == 0 && v >= count * WORD && start + v + WORD <= at + region)
+  });
+  if offsets {
+    // elements are dynamic, a word added among them isn't an element
+    infer_seq(data, start, at + region, out, false)
+  } else if WORD + count * WORD == region {
+    out.push(Link::Length { at, count, unit : WORD })
+  } else if WORD + count . div_ceil(WORD) * WORD <= region {
+    out.push(Link::Length { at, count, unit : 1 })
+  }
+}
+
+/* ----------------------------------------------------------------------------
+ Parsing
+-----------------------------------------------------------------------------*/
+
+fn parse_param(text : &str) -> Result<Param, AbiError> {
+  let text = text.trim(); let err = || AbiError::Parse(text.to_owned());
+  // type first, tuples carry their own parens
+  let (kind, rest) = match text.strip_prefix("tuple") . filter(|r| r.starts_with('(')) . unwrap_or(text) {
+    t if t.starts_with('(') => {
+      let close = matching(t, 0) . ok_or_else(err)?;
+      (ParamType::Tuple(parse_params(&t[1..close])?), &t[close + 1..])
+    },
+    t => {
+      let end = t . find(|c: char| c.is_whitespace() || c == '[') . unwrap_or(t.len());
+      (elementary(&t[..end]) . ok_or_else(err)?, &t[end..])
+    }
+  };
+  let (kind, rest) = suffixes(kind, rest) . ok_or_else(err)?;
+  // a fixed array too long to lay out, `uint256[1152921504606846976]`
+  kind.try_head_len() . ok_or_else(err)?;
+  // then markers and the name
+  let mut param = Param::new("", kind);
+  for token in rest.split_whitespace() {
+    match token {
+      "indexed"                                     => param.indexed = true,
+      // data locations and `address payable` say nothing about the encoding
+      "memory" | "calldata" | "storage" | "payable" => (),
+      _ if param.name.is_empty() && is_ident(token) => param.name = token.to_owned(),
+      _                                             => return Err(err())
+    }
+  }
+  Ok(param)
+}
+
+// array suffixes apply left to right, `uint256[2][]` is a list of pairs
+fn suffixes(mut kind : ParamType, mut rest : &str) -> Option<(ParamType, &str)> {
+  while let Some(r) = rest.strip_prefix('[') {
+    let close = r.find(']')?;
+    kind = match r[..close].trim() {
+      ""   => ParamType::Array(Box::new(kind)),
+      size => ParamType::FixedArray(Box::new(kind), size.parse() . ok()?)
+    };
+    rest = &r[close + 1..];
+  }
+  Some((kind, rest))
+}
+
+fn elementary(text : &str) -> Option<ParamType> {
+  let bits = |n : &str| match n {
+    "" => Some(256), _ => n . parse() . ok() . filter(|b: &usize| *b > 0 && *b <= 256 && b . is_multiple_of(8))
+  };
+  match text {
+    "address" => Some(ParamType::Address),
+    "bool"    => Some(ParamType::Bool),
+    "string"  => Some(ParamType::String),
+    "bytes"   => Some(ParamType::Bytes),
+    _ => if let Some(n) = text.strip_prefix("uint") { bits(n) . map(ParamType::Uint) }
+    else if let Some(n) = text.strip_prefix("int")  { bits(n) . map(ParamType::Int) }
+    else if let Some(n) = text.strip_prefix("bytes") {
+      n . parse() . ok() . filter(|b: &usize| *b > 0 && *b <= 32) . map(ParamType::FixedBytes)
+    }
+    else { None }
+  }
+}
+
+// mutability from declaration modifiers, visibility and the rest are ignored
+fn mutability(modifiers : &str) -> Option<String> {
+  let mut out = "nonpayable";
+  for token in modifiers.split_whitespace() {
+    match token {
+      "view" | "pure" | "payable" | "nonpayable" => out = token,
+      "external" | "public" | "internal" | "private" | "virtual" | "override" => (),
+      _ => return None
+    }
+  }
+  Some(out.to_owned())
+}
+
+fn is_ident(text : &str) -> bool {
+  !text.is_empty() && text . chars() . all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+  && !text . starts_with(|c: char| c.is_ascii_digit())
+}
+
+// index of the paren closing the one at `open`
+fn matching(text : &str, open : usize) -> Option<usize> {
+  let mut depth = 0;
+  for (i, c) in text[open..].char_indices() {
+    match c {
+      '(' => depth += 1,
+      ')' => { depth -= 1; if depth == 0 { return Some(open + i) } },
+      _   => ()
+    }
+  }
+  None
+}
+
+// split a value list on commas outside of brackets and parens
+fn split_list(text : &str) -> Option<Vec<&str>> {
+  if text.trim().is_empty() { return Some(Vec::new()) }
+  let mut out = Vec::new(); let mut depth = 0i32; let mut start = 0;
+  for (i, c) in text.char_indices() {
+    match c {
+      '(' | '['         => depth += 1,
+      ')' | ']'         => depth -= 1,
+      ',' if depth == 0 => { out.push(text[start..i].trim()); start = i + 1 },
+      _                 => ()
+    }
+    if depth < 0 { return None }
+  }
+  out.push(text[start..].trim());
+  Some(out)
+}
+
+// split on commas that aren't inside parens
+fn split_top(text : &str) -> Result<Vec<&str>, AbiError> {
+  if text.trim().is_empty() { return Ok(Vec::new()) }
+  let mut out = Vec::new(); let mut depth = 0i32; let mut start = 0;
+  for (i, c) in text.char_indices() {
+    match c {
+      '('              => depth += 1,
+      ')'              => depth -= 1,
+      ',' if depth == 0 => { out.push(&text[start..i]); start = i + 1 },
+      _                => ()
+    }
+    if depth < 0 { return Err(AbiError::Parse(text.to_owned())) }
+  }
+  out.push(&text[start..]);
+  Ok(out)
+}
+
+/* ----------------------------------------------------------------------------
+ Errors
+-----------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiError {
+  // text that isn't a type, parameter or signature
+  Parse      (String),
+  // a read past the end of the data, at this byte
+  OutOfBounds(usize),
+  // an offset or length word that can't be right, at this byte
+  BadOffset  (usize),
+  // bytes that don't make a value of the expected type, at this byte
+  BadValue   (usize),
+  // data that belongs to something else, topic0 or selector mismatch
+  Mismatch   (String),
+  // a value written somewhere that can't hold it
+  Type       (String)
+}
+
+impl fmt::Display for AbiError {
+  fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      AbiError::Parse(s)       => write!(f, "can't parse `{}`", s),
+      AbiError::OutOfBounds(i) => write!(f, "read out of bounds at byte {}", i),
+      AbiError::BadOffset(i)   => write!(f, "bad offset or length at byte {}", i),
+      AbiError::BadValue(i)    => write!(f, "bad value at byte {}", i),
+      AbiError::Mismatch(s)    => write!(f, "mismatch: {}", s),
+      AbiError::Type(s)        => write!(f, "wrong type: {}", s)
+    }
+  }
+}
+
+impl core::error::Error for AbiError {}
+
+const WORD : usize = 32;
+
+use crate::bai::con::{ bytes_to_hex, hex_to_bytes };
+use crate::json::{ self, Json };
+use crate::kec256::hash::{ keccak256, selector };
+use crate::kwl32::util::{ dec32, from_dec32, neg32, pad32l, pad32r };
+
+/*
+   End of abi.
+  //////////////////////////////////////////////////////////////////////// */
+/* --------------------------------------------------------------------------
+                                                      MIT License 2024 Maka  */
@@ -10,6 +10,7 @@
 // --------------------------------------------------------------------------*/
 
 pub mod con {
+  use crate::prelude::*;
 
   pub fn bytes_to_hex(bytes : &[u8]) -> String {
     bytes . iter() . map(|b| {
@@ -28,7 +29,7 @@ pub mod con {
 
   pub fn hex_to_bytes(hex : &str) -> Vec<u8> {
     let mut bytes = Vec::new();
-    if hex.len() % 2 != 0 { return bytes; }
+    if !hex.len().is_multiple_of(2) { return bytes; }
     (0..hex.len() / 2) . for_each(|i| {
       let high_nibble = hex
         . chars()
@@ -0,0 +1,238 @@
+/* ----------------------------------------------------------------------------
+   @title  : borrowed - a `View` over borrowed bytes
+   @author : Maka
+
+   @notice : `View::new` copies the call, then chunks it into owned `Word`s.
+             Scanning a block's worth of transactions that is most of the work.
+             `ViewRef` keeps the input slice and finds words as they're asked
+             for, so the read side costs nothing until a hex `String` is made.
+             Mutating goes through `to_view`, which pays for the copy once.
+// --------------------------------------------------------------------------*/
+
+use crate::prelude::*;
+
+use crate::{ Calldata, View, ViewConfig, WithSig };
+use crate::error::KawalaError;
+
+// 32 byte words after the header, the config for `ViewRef::new`. Empty strides
+// fall through to a word, so it needs nothing allocated
+static WORDS : ViewConfig = ViewConfig { header : 0, regions : Vec::new(), strides : Vec::new() };
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewRef<'a> {
+  data   : &'a [u8],
+  header : usize,
+  config : &'a ViewConfig
+}
+
+impl<'a> ViewRef<'a> {
+
+  pub fn new(data : &'a [u8], with_sig : WithSig) -> Self {
+    let header = match with_sig { WithSig::True => SIG, WithSig::False => 0 };
+    ViewRef { data, header, config : &WORDS }
+  }
+  // borrow a `Calldata` instead of a slice
+  pub fn from_calldata(call : &'a Calldata, with_sig : WithSig) -> Self {
+    Self::new(call.bytes(), with_sig)
+  }
+  // chunked by a config, which is borrowed too
+  pub fn with_config(data : &'a [u8], config : &'a ViewConfig) -> Self {
+    ViewRef { data, header : config.header_len(), config }
+  }
+
+/* ----------------------------------------------------------------------------
+ViewRef cont..              bytes
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* slices of the input, none of these allocate. Indexing clamps the same as
+     `View`, the `try_` side errors instead */
+
+  // the header, the 4 byte sig by default
+  pub fn sig_bytes(&self) -> &'a [u8] { &self.data[..self._head()] }
+  // the `arguments` portion
+  pub fn data_bytes(&self) -> &'a [u8] { &self.data[self._head()..] }
+  // the complete calldata
+  pub fn bytes(&self) -> &'a [u8] { self.data }
+  // number of words, a short tail counts as one
+  pub fn word_count(&self) -> usize {
+    match self._stride() {
+      Some(s) => self.data_bytes().len().div_ceil(s),
+      None    => self._chunks().count()
+    }
+  }
+  // a single word, past the end is the last. A zero word on an empty page
+  pub fn __word(&self, index : usize) -> &'a [u8] {
+    match self.word_count() {
+      0     => &ZERO_WORD,
+      count => { let index = core::cmp::min(index, count - 1); self._span(index, index + 1) }
+    }
+  }
+  // words `start..end` as one slice, will trunc to max
+  pub fn __words(&self, start : usize, end : usize) -> &'a [u8] {
+    let end = core::cmp::min(end, self.word_count());
+    self._span(core::cmp::min(start, end), end)
+  }
+  // as `__word`, an index past the end is an error
+  pub fn __try_word(&self, index : usize) -> Result<&'a [u8], KawalaError> {
+    let len = self.word_count();
+    match index < len {
+      true  => Ok(self._span(index, index + 1)),
+      false => Err(KawalaError::OutOfBounds { index, len })
+    }
+  }
+  // a word right padded to 32 bytes, on the stack
+  pub fn word32(&self, index : usize) -> [u8;32] {
+    pad32r(self.__word(index))
+  }
+  // bytes of the data from a byte offset, cut short at the end
+  pub fn read_bytes(&self, offset : usize, len : usize) -> &'a [u8] {
+    let data  = self.data_bytes();
+    let start = core::cmp::min(offset, data.len());
+    &data[start..core::cmp::min(offset . saturating_add(len), data.len())]
+  }
+  // as above, the range must be in the data
+  pub fn try_read_bytes(&self, offset : usize, len : usize) -> Result<&'a [u8], KawalaError> {
+    let end = offset . saturating_add(len); let data = self.data_bytes();
+    match end <= data.len() {
+      true  => Ok(&data[offset..end]),
+      false => Err(KawalaError::ByteOutOfBounds { offset : end, len : data.len() })
+    }
+  }
+
+/* ----------------------------------------------------------------------------
+ViewRef cont..              iteration
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* as on `View`, the word index and its byte offset after the sig, with
+     the word as a slice of the input. A run of words is one slice, as with
+     `__words`, where `View` hands out a `&[Word]` */
+
+  pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &'a [u8])> + 'a {
+    let mut at = 0;
+    self._chunks() . enumerate() . map(move |(i, w)| { let o = at; at += w.len(); (i, o, w) })
+  }
+  // overlapping runs of `n` words, a run is at least one
+  pub fn windows(&self, n : usize) -> impl Iterator<Item = (usize, usize, &'a [u8])> + 'a {
+    let (view, n) = (*self, core::cmp::max(n, 1));
+    let runs = (self.word_count() + 1) . saturating_sub(n);
+    self.iter() . take(runs) . map(move |(i, o, _)| (i, o, view._span(i, i + n)))
+  }
+  // runs of `n` words side by side, the last may be short
+  pub fn chunks_of(&self, n : usize) -> impl Iterator<Item = (usize, usize, &'a [u8])> + 'a {
+    let (view, n) = (*self, core::cmp::max(n, 1));
+    self.iter() . step_by(n) . map(move |(i, o, _)| (i, o, view._span(i, i + n)))
+  }
+  // words with any bit set
+  pub fn nonzero(&self) -> impl Iterator<Item = (usize, usize, &'a [u8])> + 'a {
+    self.iter() . filter(|(_, _, w)| w . iter() . any(|x| *x != 0))
+  }
+  // first word to match
+  pub fn find<F: FnMut(&[u8]) -> bool>(&self, mut f : F) -> Option<(usize, usize, &'a [u8])> {
+    self.iter() . find(|(_, _, w)| f(w))
+  }
+
+/* ----------------------------------------------------------------------------
+ViewRef cont..              hex and owned
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* the `View` callers, these allocate their `String`s but nothing else */
+
+  pub fn sig(&self) -> String { bytes_to_hex(self.sig_bytes()) }
+
+  pub fn data(&self) -> String { bytes_to_hex(self.data_bytes()) }
+
+  pub fn calldata(&self) -> String { bytes_to_hex(self.data) }
+
+  pub fn hex_0x(&self) -> String { "0x".to_owned() + &self.calldata() }
+
+  pub fn word(&self, index : usize) -> String { bytes_to_hex(self.__word(index)) }
+
+  pub fn words(&self, start : usize, end : usize) -> Vec<String> {
+    let end = core::cmp::min(end, self.word_count());
+    (core::cmp::min(start, end)..end) . map(|i| bytes_to_hex(self._span(i, i + 1))) . collect()
+  }
+
+  pub fn page(&self) -> Vec<String> {
+    self._chunks() . map(bytes_to_hex) . collect()
+  }
+
+  pub fn try_word(&self, index : usize) -> Result<String, KawalaError> {
+    self.__try_word(index) . map(bytes_to_hex)
+  }
+  // an owned view to edit, chunked the same
+  pub fn to_view(&self) -> View {
+    let config = match core::ptr::eq(self.config, &WORDS) {
+      true  => ViewConfig::new().header(self.header),
+      false => self.config.clone()
+    };
+    View::with_config(Calldata::from_bytes(self.data), config)
+  }
+
+  // *private* header length, cut short by short data
+  fn _head(&self) -> usize { core::cmp::min(self.header, self.data.len()) }
+  // *private* the stride when every word is the same length, none is over a word
+  fn _stride(&self) -> Option<usize> {
+    match (self.config.regions.is_empty(), self.config.strides.as_slice()) {
+      (true, [])  => Some(WORD),
+      (true, [s]) if *s <= WORD => Some(*s),
+      _           => None
+    }
+  }
+  // *private* words as slices, in order
+  fn _chunks(&self) -> impl Iterator<Item = &'a [u8]> + 'a {
+    let mut lens = self.config._lens() . chain(core::iter::repeat(WORD));
+    let mut rest = self.data_bytes();
+    core::iter::from_fn(move || {
+      if rest.is_empty() { return None }
+      let (word, tail) = rest . split_at(core::cmp::min(lens.next().unwrap_or(WORD), rest.len()));
+      rest = tail; Some(word)
+    })
+  }
+  // *private* words `start..end` as one slice, both in the page
+  fn _span(&self, start : usize, end : usize) -> &'a [u8] {
+    let data = self.data_bytes();
+    let (from, to) = match self._stride() {
+      Some(s) => (start * s, end * s),
+      None    => {
+        let mut lens = self._chunks() . map(|w| w.len());
+        let from = lens . by_ref() . take(start) . sum::<usize>();
+        (from, from + lens . take(end - start) . sum::<usize>())
+      }
+    };
+    &data[core::cmp::min(from, data.len())..core::cmp::min(to, data.len())]
+  }
+}
+
+// same sig and words as an owned view, however either was chunked
+impl PartialEq<View> for ViewRef<'_> {
+  fn eq(&self, other : &View) -> bool {
+    self.sig_bytes() == other.sig_bytes()
+    && self.word_count() == other.word_count()
+    && self._chunks() . zip(other.iter()) . all(|(a, (_, _, b))| a == b.bytes())
+  }
+}
+
+impl PartialEq<ViewRef<'_>> for View {
+  fn eq(&self, other : &ViewRef<'_>) -> bool { other == self }
+}
+
+impl From<ViewRef<'_>> for View {
+  fn from(view : ViewRef<'_>) -> Self { view.to_view() }
+}
+
+const SIG  : usize = 4;
+const WORD : usize = 32;
+
+static ZERO_WORD : [u8;WORD] = [0;WORD];
+
+use crate::bai::con::bytes_to_hex;
+use crate::kwl32::util::pad32r;
+
+/*
+   End of borrowed.
+  //////////////////////////////////////////////////////////////////////// */
+/* --------------------------------------------------------------------------
+                                                      MIT License 2024 Maka  */
@@ -0,0 +1,212 @@
+/* ----------------------------------------------------------------------------
+   @title  : builder - crafting calls and streams front to back
+   @author : Maka
+
+   @notice : what `basic_stream` does with append, pads, shifts and a fold,
+             written down in order. Aligned segments take a whole abi word,
+             starting on a word boundary. Packed segments take only their own
+             bytes, wherever the last one ended. Every segment is recorded in
+             a layout, and a built `View` carries the labels as word notes.
+// --------------------------------------------------------------------------*/
+
+use crate::prelude::*;
+
+use crate::{ Calldata, View, WithSig, KawalaError };
+
+// what was written where. Offsets are in the data, after the selector
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+  pub label  : String,
+  pub offset : usize,
+  pub len    : usize,
+  pub packed : bool
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CalldataBuilder {
+  selector : Option<[u8;4]>,
+  data     : Vec<u8>,
+  layout   : Vec<Segment>
+}
+
+#[allow(clippy::unused_unit)]
+impl CalldataBuilder {
+
+  pub fn new() -> Self { Self::default() }
+
+  // a 4 byte selector from hex, or from a signature. Signatures go through the
+  // abi types first, `transfer(address, uint)` hashes as `transfer(address,uint256)`.
+  // One that doesn't parse leaves zeros, `try_selector` says why
+  pub fn selector(mut self, sel : &str) -> Self {
+    self.selector = Some(match sel.contains('(') {
+      true  => canonical_signature(sel) . map(|x| selector(&x)) . unwrap_or_default(),
+      false => pad32r(&marshal_pre(sel))[..SIG] . try_into() . unwrap_or_default()
+    });
+    self
+  }
+  // as above, erroring on a signature that doesn't parse or hex that isn't 4 bytes
+  pub fn try_selector(mut self, sel : &str) -> Result<Self, KawalaError> {
+    self.selector = Some(match sel.contains('(') {
+      true  => selector(&canonical_signature(sel)?),
+      false => {
+        let bytes = parse_hex(sel.trim())?;
+        bytes . as_slice() . try_into() . map_err(|_| KawalaError::Length { expected : SIG, found : bytes.len() })?
+      }
+    });
+    Ok(self)
+  }
+  // label the last segment written, in place of its type
+  pub fn named(mut self, label : &str) -> Self {
+    if let Some(seg) = self.layout.last_mut() { seg.label = label.to_owned() } self
+  }
+  // zeros up to the next word boundary, nothing if already on one
+  pub fn pad_to_word(mut self) -> Self {
+    let pad = (WORD - self.data.len() % WORD) % WORD;
+    if pad > 0 { self._push("pad", &vec![0; pad], true) } self
+  }
+
+/* ----------------------------------------------------------------------------
+Builder cont..              aligned
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* a word each, padded the way the abi pads them. Anything packed before
+     is padded out to the boundary first */
+
+  // an address from hex, left padded
+  pub fn address(self, hex : &str) -> Self {
+    self._aligned("address", &pad32l(&_address(hex)))
+  }
+  // a `uint`, left padded
+  pub fn uint(self, value : u128) -> Self {
+    self._aligned("uint", &pad32l(&value.to_be_bytes()))
+  }
+  // an `int`, sign extended
+  pub fn int(self, value : i128) -> Self {
+    let fill = if value < 0 { 0xff } else { 0 };
+    let mut word = [fill; WORD]; word[WORD - 16..] . copy_from_slice(&value.to_be_bytes());
+    self._aligned("int", &word)
+  }
+  // a full width `uint256`, big endian. `kwl32::util::from_dec32` reads decimal
+  pub fn uint256(self, value : &[u8;WORD]) -> Self {
+    self._aligned("uint", value)
+  }
+  // a full width `int256`, already two's complement. `neg32` negates
+  pub fn int256(self, value : &[u8;WORD]) -> Self {
+    self._aligned("int", value)
+  }
+
+  pub fn boolean(self, value : bool) -> Self {
+    self._aligned("bool", &pad32l(&[value as u8]))
+  }
+  // any word from hex, left padded like a number, `3e8` is fine. Truncated to a word
+  pub fn word(self, hex : &str) -> Self {
+    self.word_from_bytes(&_number(hex))
+  }
+
+  pub fn word_from_bytes(self, bytes : &[u8]) -> Self {
+    self._aligned("word", &pad32l(&bytes[..core::cmp::min(WORD, bytes.len())]))
+  }
+
+/* ----------------------------------------------------------------------------
+Builder cont..              packed
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* exactly their own bytes, straight after whatever came before */
+
+  pub fn byte(self, value : u8) -> Self {
+    self._packed("byte", &[value])
+  }
+
+  pub fn bytes_packed(self, bytes : &[u8]) -> Self {
+    self._packed("bytes", bytes)
+  }
+  // as above from hex
+  pub fn hex_packed(self, hex : &str) -> Self {
+    self._packed("bytes", &marshal_pre(hex))
+  }
+  // 20 bytes, no padding
+  pub fn address_packed(self, hex : &str) -> Self {
+    self._packed("address", &_address(hex))
+  }
+  // a `uint` in `width` bytes, big endian. High bytes that don't fit are cut
+  pub fn uint_packed(self, value : u128, width : usize) -> Self {
+    let full  = pad32l(&value.to_be_bytes());
+    let width = core::cmp::min(width, WORD);
+    self._packed("uint", &full[WORD - width..])
+  }
+
+/* ----------------------------------------------------------------------------
+Builder cont..              output
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  // segments in the order written, padding included
+  pub fn layout(&self) -> &[Segment] { &self.layout }
+  // length of the data so far, selector not included
+  pub fn len(&self) -> usize { self.data.len() }
+
+  pub fn is_empty(&self) -> bool { self.data.is_empty() }
+
+  pub fn build(&self) -> Calldata {
+    let sel = self.selector . as_ref() . map(|x| x.as_slice()) . unwrap_or(&[]);
+    Calldata::from_bytes(&[sel, &self.data].concat())
+  }
+  // a view over the build, sig if a selector was given. Each word is noted
+  // with the labels of the segments starting in it, padding left out
+  pub fn build_view(&self) -> View {
+    let with_sig = match self.selector { Some(_) => WithSig::True, None => WithSig::False };
+    let mut view = View::new(self.build(), with_sig);
+    for index in 0..view.word_count() {
+      let words = index * WORD..(index + 1) * WORD;
+      let note  = self.layout . iter()
+        . filter(|seg| seg.label != "pad" && words.contains(&seg.offset))
+        . map(|seg| seg.label.as_str()) . collect::<Vec<_>>() . join(", ");
+      if !note.is_empty() { view.annotate(index, &note) }
+    }
+    view
+  }
+
+  // *private*
+  fn _aligned(mut self, label : &str, word : &[u8]) -> Self {
+    self = self.pad_to_word(); self._push(label, word, false); self
+  }
+
+  fn _packed(mut self, label : &str, bytes : &[u8]) -> Self {
+    self._push(label, bytes, true); self
+  }
+
+  fn _push(&mut self, label : &str, bytes : &[u8], packed : bool) -> () {
+    self.layout.push(Segment { label : label.to_owned(), offset : self.data.len(), len : bytes.len(), packed });
+    self.data.extend_from_slice(bytes)
+  }
+}
+
+// *private* hex read as a number, an odd nibble count is a leading zero short
+fn _number(hex : &str) -> Vec<u8> {
+  let hex = hex.strip_prefix("0x") . unwrap_or(hex);
+  match hex.len() % 2 { 0 => marshal_pre(hex), _ => marshal_pre(&("0".to_owned() + hex)) }
+}
+// *private* 20 bytes from hex, short ones left padded, long ones keep the end
+fn _address(hex : &str) -> [u8;20] {
+  let bytes = _number(hex); let mut out = [0u8; 20];
+  let n = core::cmp::min(20, bytes.len());
+  out[20 - n..] . copy_from_slice(&bytes[bytes.len() - n..]);
+  out
+}
+
+const SIG  : usize = 4;
+const WORD : usize = 32;
+
+use crate::marshal_pre;
+use crate::abi::canonical_signature;
+use crate::error::parse_hex;
+use crate::kec256::hash::selector;
+use crate::kwl32::util::{ pad32l, pad32r };
+
+/*
+   End of builder.
+  //////////////////////////////////////////////////////////////////////// */
+/* --------------------------------------------------------------------------
+                                                      MIT License 2024 Maka  */
@@ -0,0 +1,91 @@
+/* ----------------------------------------------------------------------------
+   @title  : contract - one abi, everything that talks to it decoded
+   @author : Maka
+
+   @notice : built from a json abi or artifact. Calls match on selector,
+             logs on topic0, reverts on error selector. Anonymous events have
+             nothing to match on, so they are tried in order.
+// --------------------------------------------------------------------------*/
+
+use crate::prelude::*;
+
+use crate::{ Log, View };
+use crate::abi::{ Abi, AbiError, Decoded, Function };
+use crate::diff::{ self, AbiDiff };
+use crate::revert::Revert;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contract {
+  abi : Abi
+}
+
+impl Contract {
+
+  pub fn new(abi : Abi) -> Self { Contract { abi } }
+
+  pub fn from_json(text : &str) -> Result<Self, AbiError> {
+    Abi::from_json(text) . map(Self::new)
+  }
+
+  // from human readable declarations, `function transfer(address to, uint256 amount)`
+  pub fn from_human(declarations : &[&str]) -> Result<Self, AbiError> {
+    Abi::from_human(declarations) . map(Self::new)
+  }
+
+  pub fn abi(&self) -> &Abi { &self.abi }
+
+  // the function a selector belongs to
+  pub fn function(&self, selector : &[u8]) -> Option<&Function> {
+    self.abi.functions . iter() . find(|f| f.selector() == selector)
+  }
+
+  // decode calldata, selector included
+  pub fn decode_call(&self, data : &[u8]) -> Result<Decoded, AbiError> {
+    let sel = data . get(..4) . ok_or(AbiError::OutOfBounds(0))?;
+    self.function(sel)
+    . ok_or_else(|| AbiError::Mismatch(format!("no function for 0x{}", bytes_to_hex(sel))))?
+    . decode_input(data)
+  }
+  // decode a view, its sig as the selector
+  pub fn decode_view(&self, view : &View) -> Result<Decoded, AbiError> {
+    self.decode_call(&view.bytes())
+  }
+  // decode return data for the function a selector belongs to
+  pub fn decode_return(&self, selector : &[u8], data : &[u8]) -> Result<Decoded, AbiError> {
+    self.function(selector)
+    . ok_or_else(|| AbiError::Mismatch(format!("no function for 0x{}", bytes_to_hex(selector))))?
+    . decode_output(data)
+  }
+  // decode a log by topic0, falling back to anonymous events
+  pub fn decode_log(&self, log : &Log) -> Result<Decoded, AbiError> {
+    let named = self.abi.events . iter() . filter(|e| !e.anonymous) . filter(|e| log.matches(e));
+    let anon  = self.abi.events . iter() . filter(|e|  e.anonymous) . filter(|e| log.matches(e));
+    named . chain(anon) . find_map(|e| log.decode(e) . ok())
+    . ok_or_else(|| AbiError::Mismatch("no event matches the log".to_owned()))
+  }
+  // decode revert data against the built ins and the contract's own errors
+  pub fn decode_revert(&self, data : &[u8]) -> Revert {
+    Revert::decode_against(data, &self.abi.errors)
+  }
+  // compare two calls to the same function parameter by parameter
+  pub fn diff_calls(&self, a : &[u8], b : &[u8]) -> Result<AbiDiff, AbiError> {
+    let sel = a . get(..4) . ok_or(AbiError::OutOfBounds(0))?;
+    let function = self.function(sel)
+      . ok_or_else(|| AbiError::Mismatch(format!("no function for 0x{}", bytes_to_hex(sel))))?;
+    diff::diff_calls(function, a, b)
+  }
+  // decode constructor arguments, the tail of creation code
+  pub fn decode_constructor(&self, args : &[u8]) -> Result<Decoded, AbiError> {
+    self.abi.constructor . as_ref()
+    . ok_or_else(|| AbiError::Mismatch("abi has no constructor".to_owned()))?
+    . decode(args)
+  }
+}
+
+use crate::bai::con::bytes_to_hex;
+
+/*
+   End of contract.
+  //////////////////////////////////////////////////////////////////////// */
+/* --------------------------------------------------------------------------
+                                                      MIT License 2024 Maka  */
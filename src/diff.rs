@@ -0,0 +1,302 @@
+/* ----------------------------------------------------------------------------
+   @title  : diff - what changed between two calls
+   @author : Maka
+
+   @notice : a byte diff of two swaps lights up every offset after a grown
+             array. Decoding both against the same function and walking the
+             values side by side tells the actual story, `path[2] added`,
+             `amountOutMin: 4997000 -> 0`. When there's no abi to lean on the
+             structural diff aligns the pages word by word instead, and
+             points at the bytes that moved inside a changed word.
+// --------------------------------------------------------------------------*/
+
+use crate::prelude::*;
+
+use core::fmt;
+use core::ops::Range;
+
+use crate::{ Calldata, View };
+use crate::abi::{ AbiError, Decoded, Function, Param, ParamType, Value };
+
+/* ----------------------------------------------------------------------------
+ Semantic diff, parameter by parameter
+-----------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+  Changed { path : String, from : Value, to : Value },
+  Added   { path : String, value : Value },
+  Removed { path : String, value : Value }
+}
+
+impl Change {
+  pub fn path(&self) -> &str {
+    match self {
+      Change::Changed { path, .. } | Change::Added { path, .. } | Change::Removed { path, .. } => path
+    }
+  }
+}
+
+impl fmt::Display for Change {
+  fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Change::Changed { path, from, to } => write!(f, "{}: {} -> {}", path, from, to),
+      Change::Added   { path, value }    => write!(f, "{} added: {}", path, value),
+      Change::Removed { path, value }    => write!(f, "{} removed: {}", path, value)
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiDiff {
+  pub function : String,
+  pub changes  : Vec<Change>
+}
+
+impl AbiDiff {
+  pub fn is_empty(&self) -> bool { self.changes.is_empty() }
+}
+
+impl fmt::Display for AbiDiff {
+  fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+    match self.changes.is_empty() {
+      true  => writeln!(f, "{}: no changes", self.function),
+      false => {
+        writeln!(f, "{}:", self.function)?;
+        self.changes . iter() . try_for_each(|c| writeln!(f, "  {}", c))
+      }
+    }
+  }
+}
+
+// decode both calls against the function and compare them
+pub fn diff_calls(function : &Function, a : &[u8], b : &[u8]) -> Result<AbiDiff, AbiError> {
+  diff_decoded(&function.inputs, &function.decode_input(a)?, &function.decode_input(b)?)
+}
+
+// compare two views with the function bound to the first
+pub fn diff_views(a : &View, b : &View) -> Result<AbiDiff, AbiError> {
+  let function = a.function()
+    . ok_or_else(|| AbiError::Mismatch("view has no bound function".to_owned()))?;
+  match a.sig() == b.sig() {
+    true  => diff_calls(function, &a.bytes(), &b.bytes()),
+    false => Err(AbiError::Mismatch(format!("sig {} vs {}", a.sig(), b.sig())))
+  }
+}
+
+// compare two decodes of the same parameter list
+pub fn diff_decoded(params : &[Param], a : &Decoded, b : &Decoded) -> Result<AbiDiff, AbiError> {
+  if a.fields.len() != params.len() || b.fields.len() != params.len() {
+    return Err(AbiError::Mismatch("decoded fields don't match the params".to_owned()))
+  }
+  let mut changes = Vec::new();
+  params . iter() . enumerate() . for_each(|(i, p)| {
+    walk(&p.kind, &label(&p.name, i, ""), &a.fields[i].1, &b.fields[i].1, &mut changes)
+  });
+  Ok(AbiDiff { function : a.name.clone(), changes })
+}
+
+// compare values of one type, descending into arrays and tuples
+#[allow(clippy::unused_unit)]
+fn walk(kind : &ParamType, path : &str, a : &Value, b : &Value, out : &mut Vec<Change>) -> () {
+  match (kind, a, b) {
+    (ParamType::Array(t), Value::Array(xs), Value::Array(ys))
+    | (ParamType::FixedArray(t, _), Value::FixedArray(xs), Value::FixedArray(ys)) => {
+      let at = |i| format!("{}[{}]", path, i);
+      xs . iter() . zip(ys) . enumerate() . for_each(|(i, (x, y))| walk(t, &at(i), x, y, out));
+      ys . iter() . enumerate() . skip(xs.len())
+      . for_each(|(i, y)| out.push(Change::Added { path : at(i), value : y.clone() }));
+      xs . iter() . enumerate() . skip(ys.len())
+      . for_each(|(i, x)| out.push(Change::Removed { path : at(i), value : x.clone() }));
+    },
+    (ParamType::Tuple(ps), Value::Tuple(xs), Value::Tuple(ys)) if xs.len() == ys.len() => {
+      ps . iter() . zip(xs . iter() . zip(ys)) . enumerate()
+      . for_each(|(i, (p, (x, y)))| walk(&p.kind, &label(&p.name, i, path), x, y, out))
+    },
+    _ if a != b => out.push(Change::Changed { path : path.to_owned(), from : a.clone(), to : b.clone() }),
+    _           => ()
+  }
+}
+
+// `parent.name`, or `parent[i]` for an unnamed component, paths `View::get` reads
+fn label(name : &str, index : usize, parent : &str) -> String {
+  match (name.is_empty(), parent.is_empty()) {
+    (true, _)      => format!("{}[{}]", parent, index),
+    (false, true)  => name.to_owned(),
+    (false, false) => format!("{}.{}", parent, name)
+  }
+}
+
+/* ----------------------------------------------------------------------------
+ Structural diff, word by word
+-----------------------------------------------------------------------------*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordOp {
+  Same     { a : usize, b : usize },
+  // same position in the alignment, different bytes. Ranges are the bytes
+  // that differ within the word, the shorter word counts as missing bytes
+  Changed  { a : usize, b : usize, ranges : Vec<Range<usize>> },
+  Inserted { b : usize },
+  Removed  { a : usize }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordDiff {
+  pub sig : (String, String),
+  pub a   : Vec<Vec<u8>>,
+  pub b   : Vec<Vec<u8>>,
+  pub ops : Vec<WordOp>
+}
+
+impl WordDiff {
+
+  // nothing inserted, removed or changed, sig included
+  pub fn is_same(&self) -> bool {
+    self.sig.0 == self.sig.1 && self.ops . iter() . all(|op| matches!(op, WordOp::Same { .. }))
+  }
+  // only the ops that aren't `Same`
+  pub fn changes(&self) -> Vec<&WordOp> {
+    self.ops . iter() . filter(|op| !matches!(op, WordOp::Same { .. })) . collect()
+  }
+
+  // render as text, one line per word. `colour` adds ansi escapes, red for
+  // what went and green for what came, otherwise changed bytes get a `^` rule
+  pub fn render(&self, colour : bool) -> String {
+    let width = self.a.len() . max(self.b.len()) . max(1) . to_string() . len();
+    let idx   = |i : Option<usize>| match i {
+      Some(i) => format!("{:>w$}", i, w = width), None => " ".repeat(width)
+    };
+    // no ranges paints the whole word
+    let paint = |bytes : &[u8], ranges : Option<&[Range<usize>]>, code : &str| -> String {
+      (0..bytes.len()) . map(|i| {
+        let hex = bytes_to_hex(&bytes[i..i + 1]);
+        match colour && ranges . map(|r| r . iter() . any(|r| r.contains(&i))) . unwrap_or(true) {
+          true  => format!("\x1b[{}m{}\x1b[0m", code, hex),
+          false => hex
+        }
+      })
+      . collect()
+    };
+    let line = |mark : &str, a : Option<usize>, b : Option<usize>, body : &str, code : &str| {
+      match colour && !code.is_empty() {
+        true  => format!("\x1b[{}m{}\x1b[0m {} {}  {}\n", code, mark, idx(a), idx(b), body),
+        false => format!("{} {} {}  {}\n", mark, idx(a), idx(b), body)
+      }
+    };
+    let mut out = match self.sig.0 == self.sig.1 {
+      true  => format!("sig {}\n", self.sig.0),
+      false => format!("sig {} -> {}\n", self.sig.0, self.sig.1)
+    };
+    self.ops . iter() . for_each(|op| match op {
+      WordOp::Same { a, b }    => out += &line(" ", Some(*a), Some(*b), &bytes_to_hex(&self.a[*a]), ""),
+      WordOp::Removed { a }    => out += &line("-", Some(*a), None, &paint(&self.a[*a], None, RED), RED),
+      WordOp::Inserted { b }   => out += &line("+", None, Some(*b), &paint(&self.b[*b], None, GREEN), GREEN),
+      WordOp::Changed { a, b, ranges } => {
+        out += &line("~", Some(*a), Some(*b), &paint(&self.a[*a], Some(ranges), RED), "");
+        out += &line(" ", None, None, &paint(&self.b[*b], Some(ranges), GREEN), "");
+        if !colour {
+          let len  = self.a[*a].len() . max(self.b[*b].len());
+          let rule = (0..len) . map(|i| match ranges . iter() . any(|r| r.contains(&i)) {
+            true => "^^", false => "  "
+          }) . collect::<String>();
+          out += &line(" ", None, None, rule.trim_end(), "");
+        }
+      }
+    });
+    out
+  }
+}
+
+impl fmt::Display for WordDiff {
+  fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.render(false))
+  }
+}
+
+// align two views word by word, sigs compared separately
+pub fn diff_words(a : &View, b : &View) -> WordDiff {
+  let page = |v : &View| v.__page() . iter() . map(|w| w.bytes().to_vec()) . collect::<Vec<_>>();
+  let (a_words, b_words) = (page(a), page(b));
+  WordDiff { sig : (a.sig(), b.sig()), ops : align(&a_words, &b_words), a : a_words, b : b_words }
+}
+
+// as above for raw calldata, chunked into words with no sig
+pub fn diff_calldata(a : &Calldata, b : &Calldata) -> WordDiff {
+  let view = |c : &Calldata| View::new(Calldata::from_bytes(c.bytes()), WithSig::False);
+  diff_words(&view(a), &view(b))
+}
+
+// longest common subsequence of words, then removed / inserted runs that
+// sit together are paired into changed words
+fn align(a : &[Vec<u8>], b : &[Vec<u8>]) -> Vec<WordOp> {
+  // common ends don't need the table
+  let pre = a . iter() . zip(b) . take_while(|(x, y)| x == y) . count();
+  let suf = a[pre..] . iter() . rev() . zip(b[pre..] . iter() . rev()) . take_while(|(x, y)| x == y) . count();
+  let (ma, mb) = (&a[pre..a.len() - suf], &b[pre..b.len() - suf]);
+  let mut table = vec![vec![0u32; mb.len() + 1]; ma.len() + 1];
+  (0..ma.len()) . rev() . for_each(|i| (0..mb.len()) . rev() . for_each(|j| {
+    table[i][j] = match ma[i] == mb[j] {
+      true  => table[i + 1][j + 1] + 1,
+      false => table[i + 1][j] . max(table[i][j + 1])
+    }
+  }));
+  let mut raw = (0..pre) . map(|i| WordOp::Same { a : i, b : i }) . collect::<Vec<_>>();
+  let (mut i, mut j) = (0, 0);
+  while i < ma.len() || j < mb.len() {
+    if i < ma.len() && j < mb.len() && ma[i] == mb[j] {
+      raw.push(WordOp::Same { a : pre + i, b : pre + j }); i += 1; j += 1;
+    } else if j < mb.len() && (i == ma.len() || table[i][j + 1] >= table[i + 1][j]) {
+      raw.push(WordOp::Inserted { b : pre + j }); j += 1;
+    } else {
+      raw.push(WordOp::Removed { a : pre + i }); i += 1;
+    }
+  }
+  (0..suf) . for_each(|k| raw.push(WordOp::Same { a : a.len() - suf + k, b : b.len() - suf + k }));
+  pair(raw, a, b)
+}
+
+// within each run between `Same`s, line removed words up with inserted ones
+fn pair(raw : Vec<WordOp>, a : &[Vec<u8>], b : &[Vec<u8>]) -> Vec<WordOp> {
+  let mut out = Vec::new(); let mut gone = Vec::new(); let mut came = Vec::new();
+  let flush = |out : &mut Vec<WordOp>, gone : &mut Vec<usize>, came : &mut Vec<usize>| {
+    let n = gone.len() . min(came.len());
+    gone . iter() . zip(came . iter()) . for_each(|(x, y)| {
+      out.push(WordOp::Changed { a : *x, b : *y, ranges : byte_ranges(&a[*x], &b[*y]) })
+    });
+    gone[n..] . iter() . for_each(|x| out.push(WordOp::Removed { a : *x }));
+    came[n..] . iter() . for_each(|y| out.push(WordOp::Inserted { b : *y }));
+    gone.clear(); came.clear();
+  };
+  raw . into_iter() . for_each(|op| match op {
+    WordOp::Removed { a }  => gone.push(a),
+    WordOp::Inserted { b } => came.push(b),
+    same                   => { flush(&mut out, &mut gone, &mut came); out.push(same) }
+  });
+  flush(&mut out, &mut gone, &mut came);
+  out
+}
+
+// ranges of byte positions that differ, missing bytes count as different
+fn byte_ranges(a : &[u8], b : &[u8]) -> Vec<Range<usize>> {
+  let mut out : Vec<Range<usize>> = Vec::new();
+  (0..a.len() . max(b.len())) . filter(|i| a.get(*i) != b.get(*i)) . for_each(|i| {
+    match out.last_mut() {
+      Some(r) if r.end == i => r.end = i + 1,
+      _                     => out.push(i..i + 1)
+    }
+  });
+  out
+}
+
+const RED   : &str = "31";
+const GREEN : &str = "32";
+
+use crate::WithSig;
+use crate::bai::con::bytes_to_hex;
+
+/*
+   End of diff.
+  //////////////////////////////////////////////////////////////////////// */
+/* --------------------------------------------------------------------------
+                                                      MIT License 2024 Maka  */
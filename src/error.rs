@@ -0,0 +1,88 @@
+/* ----------------------------------------------------------------------------
+   @title  : error - one error type for the crate
+   @author : Maka
+
+   @notice : the `try_` side of `View` and friends. Where the plain methods
+             clamp to the last word or default a bad read, these say what went
+             wrong, so an out of bounds index can't quietly edit the wrong word.
+// --------------------------------------------------------------------------*/
+
+use crate::prelude::*;
+
+use core::fmt;
+
+use crate::abi::AbiError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KawalaError {
+  // a word index past the end of the page
+  OutOfBounds { index : usize, len : usize },
+  // a byte offset or range end past the end of the data
+  ByteOutOfBounds { offset : usize, len : usize },
+  // a range whose start is after its end
+  BadRange { start : usize, end : usize },
+  // nothing to take, the page is empty
+  Empty,
+  // text that isn't hex, odd length or a stray character
+  Hex(String),
+  // more bytes than the target can hold, a word or a sig
+  TooLong { max : usize, found : usize },
+  // a sig that isn't exactly 4 bytes
+  Length { expected : usize, found : usize },
+  // a template line that can't be read, 1 based, and the text at fault
+  Template { line : usize, text : String },
+  // a template hole, or template, with nothing to fill it
+  Missing(String),
+  // a fill its hole's type can't hold
+  Mistyped { name : String, kind : String, value : String },
+  // a fill with no hole to go in
+  Unused(String),
+  // anything the abi layer turned up
+  Abi(AbiError)
+}
+
+impl fmt::Display for KawalaError {
+  fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      KawalaError::OutOfBounds { index, len }      => write!(f, "word {} out of bounds, page has {}", index, len),
+      KawalaError::ByteOutOfBounds { offset, len } => write!(f, "byte {} out of bounds, data has {}", offset, len),
+      KawalaError::BadRange { start, end }         => write!(f, "range {}..{} starts after it ends", start, end),
+      KawalaError::Empty                           => write!(f, "page is empty"),
+      KawalaError::Hex(s)                          => write!(f, "not hex `{}`", s),
+      KawalaError::TooLong { max, found }          => write!(f, "{} bytes where at most {} fit", found, max),
+      KawalaError::Length { expected, found }      => write!(f, "{} bytes where {} are expected", found, expected),
+      KawalaError::Template { line, text }         => write!(f, "template line {} can't be read at `{}`", line, text),
+      KawalaError::Missing(name)                   => write!(f, "nothing to fill `{}`", name),
+      KawalaError::Mistyped { name, kind, value }  => write!(f, "`{}` is {}, can't hold `{}`", name, kind, value),
+      KawalaError::Unused(name)                    => write!(f, "no hole named `{}`", name),
+      KawalaError::Abi(e)                          => write!(f, "{}", e)
+    }
+  }
+}
+
+impl core::error::Error for KawalaError {
+  fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+    match self { KawalaError::Abi(e) => Some(e), _ => None }
+  }
+}
+
+impl From<AbiError> for KawalaError {
+  fn from(e : AbiError) -> Self { KawalaError::Abi(e) }
+}
+
+// strict hex, prefix optional, empty is fine
+pub fn parse_hex(text : &str) -> Result<Vec<u8>, KawalaError> {
+  let hex = text.strip_prefix("0x") . unwrap_or(text);
+  match hex.len() . is_multiple_of(2) && hex . chars() . all(|c| c.is_ascii_hexdigit()) {
+    true  => Ok(hex_to_bytes(hex)),
+    false => Err(KawalaError::Hex(text.to_owned()))
+  }
+}
+
+use crate::bai::con::hex_to_bytes;
+
+/*
+   End of error.
+  //////////////////////////////////////////////////////////////////////// */
+/* --------------------------------------------------------------------------
+                                                      MIT License 2024 Maka  */
@@ -0,0 +1,173 @@
+/* ----------------------------------------------------------------------------
+   @title  : json - just enough json to read abi files and artifacts
+   @author : Maka
+
+   @notice : small recursive reader, no serializer. Numbers are kept as
+             their source text since abi files only ever need them as labels.
+   errors  : a malformed document returns None, same garbage in garbage out
+             spirit as bai::con, callers decide what a miss means to them.
+             Nesting past `MAX_DEPTH` is malformed too, the reader recurses
+             and hostile input shouldn't get to take the stack with it.
+// --------------------------------------------------------------------------*/
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+  Null,
+  Bool(bool),
+  Num (String),
+  Str (String),
+  Arr (Vec<Json>),
+  Obj (Vec<(String, Json)>)
+}
+
+impl Json {
+  // member lookup on an object, None for anything else
+  pub fn get(&self, key : &str) -> Option<&Json> {
+    match self {
+      Json::Obj(members) => members . iter() . find(|(k, _)| k == key) . map(|(_, v)| v),
+      _                  => None
+    }
+  }
+  // str contents, None if not a string
+  pub fn as_str(&self) -> Option<&str> {
+    match self { Json::Str(s) => Some(s.as_str()), _ => None }
+  }
+  // array elements, None if not an array
+  pub fn as_array(&self) -> Option<&[Json]> {
+    match self { Json::Arr(a) => Some(a.as_slice()), _ => None }
+  }
+  // bool value, None if not a bool
+  pub fn as_bool(&self) -> Option<bool> {
+    match self { Json::Bool(b) => Some(*b), _ => None }
+  }
+}
+
+// parse a complete document, trailing garbage is a miss
+pub fn parse(text : &str) -> Option<Json> {
+  let mut reader = Reader { src : text.as_bytes(), pos : 0, depth : 0 };
+  let value = reader.value()?;
+  reader.skip_ws();
+  match reader.pos == reader.src.len() { true => Some(value), false => None }
+}
+
+/* ----------------------------------------------------------------------------
+ Reader
+-----------------------------------------------------------------------------*/
+
+struct Reader<'a> {
+  src   : &'a [u8],
+  pos   : usize,
+  depth : usize
+}
+
+#[allow(clippy::unused_unit)]
+impl Reader<'_> {
+
+  fn peek(&self) -> Option<u8> { self.src.get(self.pos) . copied() }
+
+  fn skip_ws(&mut self) -> () {
+    while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() { self.pos += 1 }
+  }
+
+  fn eat(&mut self, byte : u8) -> Option<()> {
+    self.skip_ws();
+    match self.peek() == Some(byte) { true => { self.pos += 1; Some(()) }, false => None }
+  }
+
+  fn literal(&mut self, word : &str, value : Json) -> Option<Json> {
+    let end = self.pos + word.len();
+    match self.src.get(self.pos..end) == Some(word.as_bytes()) {
+      true  => { self.pos = end; Some(value) },
+      false => None
+    }
+  }
+
+  fn value(&mut self) -> Option<Json> {
+    self.skip_ws();
+    match self.peek()? {
+      b'{'                   => self.object(),
+      b'['                   => self.array(),
+      b'"'                   => self.string() . map(Json::Str),
+      b't'                   => self.literal("true",  Json::Bool(true)),
+      b'f'                   => self.literal("false", Json::Bool(false)),
+      b'n'                   => self.literal("null",  Json::Null),
+      b'-' | b'0'..=b'9'     => self.number(),
+      _                      => None
+    }
+  }
+
+  fn object(&mut self) -> Option<Json> {
+    self.eat(b'{')?; self.deeper()?; let mut members = Vec::new();
+    if self.eat(b'}') . is_some() { return self.close(Json::Obj(members)) }
+    loop {
+      self.skip_ws();
+      let key = self.string()?; self.eat(b':')?;
+      members . push((key, self.value()?));
+      if self.eat(b',') . is_some() { continue }
+      self.eat(b'}')?; return self.close(Json::Obj(members))
+    }
+  }
+
+  fn array(&mut self) -> Option<Json> {
+    self.eat(b'[')?; self.deeper()?; let mut items = Vec::new();
+    if self.eat(b']') . is_some() { return self.close(Json::Arr(items)) }
+    loop {
+      items . push(self.value()?);
+      if self.eat(b',') . is_some() { continue }
+      self.eat(b']')?; return self.close(Json::Arr(items))
+    }
+  }
+  // one more level open, None past the cap
+  fn deeper(&mut self) -> Option<()> {
+    self.depth += 1;
+    match self.depth <= MAX_DEPTH { true => Some(()), false => None }
+  }
+  // a level closed with its value
+  fn close(&mut self, value : Json) -> Option<Json> {
+    self.depth -= 1; Some(value)
+  }
+
+  fn number(&mut self) -> Option<Json> {
+    let start = self.pos;
+    while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') = self.peek() { self.pos += 1 }
+    core::str::from_utf8(&self.src[start..self.pos]) . ok() . map(|s| Json::Num(s.to_owned()))
+  }
+
+  fn string(&mut self) -> Option<String> {
+    if self.peek()? != b'"' { return None } self.pos += 1;
+    let mut out = Vec::new();
+    loop {
+      let byte = self.peek()?; self.pos += 1;
+      match byte {
+        b'"'  => return String::from_utf8(out) . ok(),
+        b'\\' => {
+          let esc = self.peek()?; self.pos += 1;
+          match esc {
+            b'n' => out.push(b'\n'), b't' => out.push(b'\t'), b'r' => out.push(b'\r'),
+            b'b' => out.push(0x08),  b'f' => out.push(0x0c),
+            b'u' => {
+              let hex = core::str::from_utf8(self.src.get(self.pos..self.pos + 4)?) . ok()?;
+              let ch  = char::from_u32(u32::from_str_radix(hex, 16) . ok()?)
+                      . unwrap_or(char::REPLACEMENT_CHARACTER);
+              self.pos += 4; let mut buf = [0u8;4];
+              out . extend_from_slice(ch.encode_utf8(&mut buf).as_bytes())
+            },
+            other => out.push(other)
+          }
+        },
+        other => out.push(other)
+      }
+    }
+  }
+}
+
+// deepest nesting read, abi files need a handful
+const MAX_DEPTH : usize = 256;
+
+/*
+   End of json.
+  //////////////////////////////////////////////////////////////////////// */
+/* --------------------------------------------------------------------------
+                                                      MIT License 2024 Maka  */
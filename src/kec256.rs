@@ -0,0 +1,96 @@
+//----------------------------------------------------------------------------//
+/*                                                     MIT License 2024 Maka  */
+// -------------------------------------------------------------------------- -
+/*
+ @title  : kec256::hash
+ @notice : keccak-256 as used by the evm, selectors and topics hang off this
+ @author : Maka
+*/
+/* ----------------------------------------------------------------------------
+    Original keccak padding (0x01), not the nist sha3 one (0x06). Rate is 136.
+-----------------------------------------------------------------------------*/
+
+pub mod hash {
+
+  // output the keccak-256 digest of the input
+  pub fn keccak256(bytes: &[u8]) -> [u8;32] {
+    let mut state = [0u64;25];
+    let mut chunks = bytes . chunks_exact(RATE);
+    chunks . by_ref() . for_each(|block| { _absorb(&mut state, block); f1600(&mut state) });
+    // pad the remainder, single block since it is under the rate
+    let rem = chunks.remainder();
+    let mut last = [0u8;RATE]; last[..rem.len()] . copy_from_slice(rem);
+    last[rem.len()] ^= 0x01; last[RATE - 1] ^= 0x80;
+    _absorb(&mut state, &last); f1600(&mut state);
+    // squeeze, 32 bytes fits inside the first block
+    let mut out = [0u8;32];
+    out . chunks_mut(8) . zip(state.iter()) . for_each(|(o, lane)| {
+      o . copy_from_slice(&lane.to_le_bytes())
+    });
+    out
+  }
+  // first 4 bytes of the digest, the function selector for a text signature
+  pub fn selector(text: &str) -> [u8;4] {
+    let digest = keccak256(text.as_bytes());
+    [digest[0], digest[1], digest[2], digest[3]]
+  }
+
+//-----------------------------------------------------------------------------
+
+  // xor a full block into the state, lane by little endian lane
+  fn _absorb(state: &mut [u64;25], block: &[u8]) {
+    block . chunks(8) . zip(state.iter_mut()) . for_each(|(b, lane)| {
+      let mut buf = [0u8;8]; buf . copy_from_slice(b);
+      *lane ^= u64::from_le_bytes(buf)
+    })
+  }
+  // the permutation, 24 rounds of theta rho pi chi iota
+  fn f1600(st: &mut [u64;25]) {
+    RC . iter() . for_each(|rc| {
+      // theta
+      let mut bc = [0u64;5];
+      (0..5) . for_each(|i| bc[i] = st[i] ^ st[i + 5] ^ st[i + 10] ^ st[i + 15] ^ st[i + 20]);
+      (0..5) . for_each(|i| {
+        let t = bc[(i + 4) % 5] ^ bc[(i + 1) % 5] . rotate_left(1);
+        (0..25) . step_by(5) . for_each(|j| st[j + i] ^= t)
+      });
+      // rho and pi
+      let mut t = st[1];
+      ROTC . iter() . zip(PILN.iter()) . for_each(|(r, &j)| {
+        let tmp = st[j]; st[j] = t . rotate_left(*r); t = tmp
+      });
+      // chi
+      (0..25) . step_by(5) . for_each(|j| {
+        let row = [st[j], st[j + 1], st[j + 2], st[j + 3], st[j + 4]];
+        (0..5) . for_each(|i| st[j + i] ^= !row[(i + 1) % 5] & row[(i + 2) % 5])
+      });
+      // iota
+      st[0] ^= rc
+    })
+  }
+
+  const RATE : usize = 136;
+
+  const RC   : [u64;24] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808a, 0x8000000080008000,
+    0x000000000000808b, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008a, 0x0000000000000088, 0x0000000080008009, 0x000000008000000a,
+    0x000000008000808b, 0x800000000000008b, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800a, 0x800000008000000a,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008
+  ];
+  const ROTC : [u32;24] = [
+     1,  3,  6, 10, 15, 21, 28, 36, 45, 55,  2, 14,
+    27, 41, 56,  8, 25, 43, 62, 18, 39, 61, 20, 44
+  ];
+  const PILN : [usize;24] = [
+    10,  7, 11, 17, 18,  3,  5, 16,  8, 21, 24,  4,
+    15, 23, 19, 13, 12,  2, 20, 14, 22,  9,  6,  1
+  ];
+}
+
+/*
+   End of hash.
+  //////////////////////////////////////////////////////////////////////// */
+/* --------------------------------------------------------------------------
+                                                      MIT License 2024 Maka  */
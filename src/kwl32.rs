@@ -11,7 +11,8 @@
 -----------------------------------------------------------------------------*/
 
 pub mod util {
-use std::cmp::{ min, max };
+  use crate::prelude::*;
+use core::cmp::{ min, max };
 
   // output the result of right padding input with zeros
   pub fn pad32r(bytes: &[u8]) -> [u8;32] {
@@ -22,7 +23,7 @@ use std::cmp::{ min, max };
   // output the result of left padding input with zeros
   pub fn pad32l(bytes: &[u8])  -> [u8;32] {
     let ost = min(32, bytes.len() as i32) as usize;
-    let pad = max(0, 32 as i32 - bytes.len() as i32) as usize;
+    let pad = max(0, 32 - bytes.len() as i32) as usize;
     let mut padded = [0u8;32]; padded[pad..] . copy_from_slice(&bytes[..ost]);
     padded
   }
@@ -74,6 +75,45 @@ use std::cmp::{ min, max };
   pub fn not32(a: &[u8;32]             ) -> [u8;32] {
     let mut buf = [0u8;32]; (0..32) . for_each(|i|buf[i] = notu8(a[i])); buf
   }
+  // output the two's complement negation of the input
+  pub fn neg32(a: &[u8;32]) -> [u8;32] {
+    let mut buf = not32(a); let mut carry = true;
+    buf . iter_mut() . rev() . for_each(|b| if carry { let (v, c) = b.overflowing_add(1); *b = v; carry = c });
+    buf
+  }
+
+//-----------------------------------------------------------------------------
+
+  // output the input as a big endian unsigned decimal string
+  pub fn dec32(bytes: &[u8;32]) -> String {
+    let mut buf = *bytes; let mut digits = Vec::new();
+    while buf . iter() . any(|b| *b != 0) {
+      let mut rem = 0u32;
+      buf . iter_mut() . for_each(|b| {
+        let cur = (rem << 8) | *b as u32; *b = (cur / 10) as u8; rem = cur % 10
+      });
+      digits . push(b'0' + rem as u8);
+    }
+    if digits.is_empty() { return String::from("0") }
+    digits . iter() . rev() . map(|d| *d as char) . collect()
+  }
+
+  // output the word for an unsigned decimal string, None if it won't fit
+  pub fn from_dec32(text: &str) -> Option<[u8;32]> {
+    if text.is_empty() { return None }
+    let mut buf = [0u8;32];
+    for c in text.chars() {
+      let mut carry = c.to_digit(10)?;
+      buf . iter_mut() . rev() . for_each(|b| {
+        let cur = *b as u32 * 10 + carry; *b = cur as u8; carry = cur >> 8
+      });
+      if carry != 0 { return None }
+    }
+    Some(buf)
+  }
+
+//-----------------------------------------------------------------------------
+
   // passable comparitors
   fn xoru8(a: u8, b: u8) -> u8 { a ^ b } fn andu8(a: u8, b: u8) -> u8 { a & b }
   fn notu8(a: u8)        -> u8 { ! a   } fn oru8 (a: u8, b: u8) -> u8 { a | b }
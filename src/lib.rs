@@ -5,11 +5,17 @@
                                                            MIT License 2024  */
 // --------------------------------------------------------------------------*/
 
+/* std by default, `alloc` is all the rest needs. Without the `std` feature
+   there's no printing, no file io and no shared bundled registry          */
+#![cfg_attr(not(feature = "std"), no_std)]
+#[macro_use]
+extern crate alloc;
+
 /* Bytes type                                                           ʕ·͡ᴥ·ʔ
 -----------------------------------------------------------------------------*/
   ///////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Bytes {
  Bytes4 ([u8;SIG_LEN]),
  Bytes32([u8;WORD_LEN]),
@@ -30,9 +36,11 @@ impl Bytes {
   }
 
   pub fn len(&self) -> usize  { self.bytes().len() }
+
+  pub fn is_empty(&self) -> bool { self.bytes().is_empty() }
 }
 
-impl<I: std::slice::SliceIndex<[u8]>> std::ops::Index<I> for Bytes {
+impl<I: core::slice::SliceIndex<[u8]>> core::ops::Index<I> for Bytes {
   type Output = I::Output;
   fn index(&self, i: I) -> &Self::Output {
     match self {
@@ -43,23 +51,37 @@ impl<I: std::slice::SliceIndex<[u8]>> std::ops::Index<I> for Bytes {
   }
 }
 
+// equal, hashed and ordered by content, whichever variant holds it
 impl PartialEq for Bytes {
   fn eq(&self, other: &Self) -> bool {
-    match (self, other) {
-      ( Bytes::Bytes4(x) , Bytes::Bytes4(y) ) => x == y,
-      ( Bytes::Bytes32(x), Bytes::Bytes32(y)) => x == y,
-      ( Bytes::Array(x)  , Bytes::Array(y)  ) => x == y,
-      _                                       => false    
-    }
+    self.bytes() == other.bytes()
   }
 }
 
+impl Eq for Bytes {}
+
+impl core::hash::Hash for Bytes {
+  fn hash<H: core::hash::Hasher>(&self, state: &mut H) { self.bytes().hash(state) }
+}
+
+impl PartialOrd for Bytes {
+  fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Bytes {
+  fn cmp(&self, other: &Self) -> core::cmp::Ordering { self.bytes().cmp(other.bytes()) }
+}
+
+impl AsRef<[u8]> for Bytes {
+  fn as_ref(&self) -> &[u8] { self.bytes() }
+}
+
 /* ----------------------------------------------------------------------------
  Calldata structure
 -----------------------------------------------------------------------------*/
   ///////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Calldata {
   data : Bytes
 }
@@ -81,6 +103,8 @@ impl Calldata {
     self.data.len()
   }
 
+  pub fn is_empty(&self) -> bool { self.data.is_empty() }
+
   pub fn from_bytes(bytes : &[u8]) -> Self {
     Self::new(Bytes::Array(bytes.to_vec()))
   }
@@ -88,16 +112,43 @@ impl Calldata {
   pub fn from_hex(string : &str) -> Self {
     Self::new(Bytes::Array(marshal_pre(string)))
   }
+  // as above, rejecting text that isn't hex
+  pub fn try_from_hex(string : &str) -> Result<Self, KawalaError> {
+    parse_hex(string) . map(|x| Self::new(Bytes::Array(x)))
+  }
 
   pub fn hex_0x(&self) -> String {
     "0x".to_owned() + &self.data.hex()
   }
+  // craft a call or stream segment by segment, see `CalldataBuilder`
+  pub fn builder() -> CalldataBuilder {
+    CalldataBuilder::new()
+  }
+  // classic hexdump with an ascii gutter, see `Hexdump` for the options
+  pub fn hexdump(&self) -> String {
+    Hexdump::new().dump(self.bytes())
+  }
+  // reads a hexdump back, edited or not
+  pub fn from_hexdump(text : &str) -> Result<Self, KawalaError> {
+    render::parse_hexdump(text) . map(|x| Self::new(Bytes::Array(x)))
+  }
 }
 
-impl PartialEq for Calldata {
-  fn eq(&self, other: &Self) -> bool {
-    self.data == other.data
-  }
+impl AsRef<[u8]> for Calldata {
+  fn as_ref(&self) -> &[u8] { self.bytes() }
+}
+
+impl core::str::FromStr for Calldata {
+  type Err = KawalaError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> { Self::try_from_hex(s) }
+}
+
+impl From<&[u8]> for Calldata {
+  fn from(bytes: &[u8]) -> Self { Self::from_bytes(bytes) }
+}
+
+impl From<Vec<u8>> for Calldata {
+  fn from(bytes: Vec<u8>) -> Self { Self::new(Bytes::Array(bytes)) }
 }
 
 /* ----------------------------------------------------------------------------
@@ -105,7 +156,7 @@ impl PartialEq for Calldata {
 -----------------------------------------------------------------------------*/
   ///////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Signature {
   data : Bytes
 }
@@ -128,28 +179,61 @@ impl Signature {
     self.data.len()
   }
 
+  pub fn is_empty(&self) -> bool { self.data.is_empty() }
+
   pub fn from_bytes(array : &[u8]) -> Self {
     Self::new(Bytes::Bytes4(
-      array[..std::cmp::min(SIG_LEN, array.len())]
+      array[..core::cmp::min(SIG_LEN, array.len())]
       . try_into()
       . unwrap_or(EMPTY_SIG)
     ))
   }
 
+  // exactly 4 bytes or an error, where `from_bytes` zeroes a short sig
+  pub fn try_from_bytes(array : &[u8]) -> Result<Self, KawalaError> {
+    match array.len() == SIG_LEN {
+      true  => Ok(Self::from_bytes(array)),
+      false => Err(KawalaError::Length { expected : SIG_LEN, found : array.len() })
+    }
+  }
+  // as above from hex, prefix optional
+  pub fn try_from_hex(string : &str) -> Result<Self, KawalaError> {
+    Self::try_from_bytes(&parse_hex(string)?)
+  }
+  // a header of any length, a 4 byte one is a regular sig
+  pub fn from_header(array : &[u8]) -> Self {
+    match array.len() == SIG_LEN {
+      true  => Self::from_bytes(array),
+      false => Self::new(Bytes::Array(array.to_vec()))
+    }
+  }
+
   pub fn from_hex(string : &str) -> Self {
     let bytes = marshal_pre(string);
     Self::new(Bytes::Bytes4({
-      bytes[..std::cmp::min(SIG_LEN, bytes.len())]
+      bytes[..core::cmp::min(SIG_LEN, bytes.len())]
       . try_into()
       . unwrap_or(EMPTY_SIG)
     }))
   }
 }
 
-impl PartialEq for Signature {
-  fn eq(&self, other: &Self) -> bool {
-    self.data == other.data
-  }
+impl AsRef<[u8]> for Signature {
+  fn as_ref(&self) -> &[u8] { self.bytes() }
+}
+
+impl core::str::FromStr for Signature {
+  type Err = KawalaError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> { Self::try_from_hex(s) }
+}
+
+impl TryFrom<&[u8]> for Signature {
+  type Error = KawalaError;
+  fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> { Self::try_from_bytes(bytes) }
+}
+
+impl From<[u8;SIG_LEN]> for Signature {
+  fn from(bytes: [u8;SIG_LEN]) -> Self { Self::new(Bytes::Bytes4(bytes)) }
 }
 
 /*----------------------------------------------------------------------------
@@ -157,52 +241,67 @@ impl PartialEq for Signature {
 -----------------------------------------------------------------------------*/
   ///////////////////////////////////////////////////////////////////////////
 
-  /* Core view type */
+  /* Core view type. A word lives inline, 32 bytes and a length, so a short
+     tail costs the same as a full word and a `Word` is `Copy`. Bytes past the
+     length are always zero, which is the padding `as_bytes32` hands out.
 
-#[derive(Debug)]
+     Equality, hashing and order go by the padded 32 bytes, the word the
+     word ops see. Where a word came from or how it was built doesn't matter,
+     and neither does length, a short word is equal to its padded self. `len`
+     and `bytes` are there when the logical content is what counts */
+
+#[derive(Debug, Clone, Copy)]
 pub struct Word {
-  data : Bytes
+  data : [u8;WORD_LEN],
+  len  : u8
 }
 
 impl Word {
 
-  fn new(bytes : Bytes) -> Self {
-    Word { data : bytes }
+  // *private* at most a word, the rest left zero
+  fn new(bytes : &[u8]) -> Self {
+    let len = core::cmp::min(WORD_LEN, bytes.len());
+    let mut data = EMPTY_BYTES32; data[..len].copy_from_slice(&bytes[..len]);
+    Word { data, len : len as u8 }
   }
 
   pub fn bytes(&self) -> &[u8] {
-    self.data.bytes()
+    &self.data[..self.len as usize]
   }
 
   pub fn hex(&self)  -> String {
-    self.data.hex()
+    bytes_to_hex(self.bytes())
+  }
+  // the bytes as a `Bytes`, a full word needs no allocation
+  pub fn data(&self) -> Bytes {
+    match self.len() == WORD_LEN {
+      true  => Bytes::Bytes32(self.data),
+      false => Bytes::Array(self.bytes().to_vec())
+    }
   }
 
-  pub fn data(&self) -> &Bytes {
-    &self.data
-  }   
+  pub fn len(&self)  -> usize  { self.len as usize }
 
-  pub fn len(&self)  -> usize  { self.bytes().len() }
+  pub fn is_empty(&self) -> bool { self.len == 0 }
 
   pub fn from_bytes(array : &[u8]) -> Self {
-    let slice = &array[..std::cmp::min(WORD_LEN, array.len())];
-    match array.len() == WORD_LEN {
-      true => Self::new(Bytes::Bytes32(
-        slice
-      . try_into()
-      . unwrap_or(EMPTY_BYTES32)
-      )),
-      false => Self::new(Bytes::Array(
-        slice
-      . try_into()
-      . unwrap_or(Vec::from(EMPTY_U8_SLICE))
-      ))
-    }
+    Self::new(array)
   }
 
   pub fn from_hex(string : &str) -> Self {
     Self::from_bytes(marshal_pre(string).as_slice())
   }
+  // at most a word of bytes or an error, where `from_bytes` truncates
+  pub fn try_from_bytes(array : &[u8]) -> Result<Self, KawalaError> {
+    match array.len() <= WORD_LEN {
+      true  => Ok(Self::from_bytes(array)),
+      false => Err(KawalaError::TooLong { max : WORD_LEN, found : array.len() })
+    }
+  }
+  // as above from hex, prefix optional
+  pub fn try_from_hex(string : &str) -> Result<Self, KawalaError> {
+    Self::try_from_bytes(&parse_hex(string)?)
+  }
 
   /*
   So many of the functions want to work on a common 32 byte word, and in the
@@ -213,25 +312,143 @@ impl Word {
   add command byte > right pad > xor amount and address to create stream, etc.
   */
   pub fn as_bytes32(&self) -> [u8;32] {
-    match &self.data {
-      Bytes::Bytes32(x) => *x,
-      Bytes::Array(x)   => pad32r(&x . as_slice()),
-      Bytes::Bytes4(x)  => pad32r(x)
-    }
+    self.data
   }
-
   pub fn hex_0x(&self) -> String {
-    "0x".to_owned() + &self.data.hex()
+    "0x".to_owned() + &self.hex()
   }
 
+  pub fn is_zero(&self) -> bool { self.bytes() . iter() . all(|x| *x == 0) }
 }
 
+// by the padded 32 bytes, `01` and `0100..00` are equal. Compare `bytes()` where length counts
 impl PartialEq for Word {
-  fn eq(&self, other: &Self) -> bool {
-    self.data == other.data
+  fn eq(&self, other: &Self) -> bool { self.data == other.data }
+}
+
+impl Eq for Word {}
+
+impl core::hash::Hash for Word {
+  fn hash<H: core::hash::Hasher>(&self, state: &mut H) { self.data.hash(state) }
+}
+
+impl PartialOrd for Word {
+  fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Word {
+  fn cmp(&self, other: &Self) -> core::cmp::Ordering { self.data.cmp(&other.data) }
+}
+
+impl AsRef<[u8]> for Word {
+  fn as_ref(&self) -> &[u8] { self.bytes() }
+}
+
+impl core::str::FromStr for Word {
+  type Err = KawalaError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> { Self::try_from_hex(s) }
+}
+
+impl TryFrom<&[u8]> for Word {
+  type Error = KawalaError;
+  fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> { Self::try_from_bytes(bytes) }
+}
+
+impl From<[u8;WORD_LEN]> for Word {
+  fn from(bytes: [u8;WORD_LEN]) -> Self { Word { data : bytes, len : WORD_LEN as u8 } }
+}
+
+/* ----------------------------------------------------------------------------
+ View config
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* not everything is a 4 byte sig over 32 byte words. A bot stream with a
+     command byte and 20 byte records, an 8 byte anchor discriminator.. the
+     header length and how the rest is chunked are up to the config. Chunks
+     are still `Word`s, so a stride over 32 is cut into words and what's left,
+     a 52 byte record reads as 32 and 20. Word ops pad a short chunk out like
+     they always have */
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewConfig {
+  header  : usize,
+  // (byte length, stride) regions chunked first, in order
+  regions : Vec<(usize, usize)>,
+  // strides cycled over whatever follows the regions
+  strides : Vec<usize>
+}
+
+impl ViewConfig {
+
+  // a 4 byte sig over 32 byte words, same as `WithSig::True`
+  pub fn new() -> Self {
+    ViewConfig { header : SIG_LEN, regions : Vec::new(), strides : vec![WORD_LEN] }
+  }
+  // header length in bytes, 0 for none
+  pub fn header(mut self, len : usize) -> Self {
+    self.header = len; self
+  }
+  // chunk stride for the data, a record longer than a word spans several
+  pub fn stride(mut self, stride : usize) -> Self {
+    self.strides = vec![_stride(stride)]; self
+  }
+  // strides cycled over the data, `&[20, 32]` for 20 then 32 byte records
+  pub fn strides(mut self, strides : &[usize]) -> Self {
+    self.strides = strides . iter() . map(|x| _stride(*x)) . collect();
+    if self.strides.is_empty() { self.strides.push(WORD_LEN) }
+    self
+  }
+  // a region of `len` bytes with its own stride, regions come before the rest
+  pub fn region(mut self, len : usize, stride : usize) -> Self {
+    if len > 0 { self.regions.push((len, _stride(stride))) } self
+  }
+
+  pub fn header_len(&self) -> usize { self.header }
+
+  // chunk data whose first byte sits at data offset `from`, cutting at the
+  // config's boundaries. Starting between two, the first chunk runs to the next
+  pub fn chunks(&self, data : &[u8], from : usize) -> Vec<Word> {
+    let mut lens = self._lens(); let mut end = ZERO_INDEX;
+    while end <= from { end += lens.next().unwrap_or(WORD_LEN) }
+    let mut out = Vec::new(); let mut at = ZERO_INDEX; let mut len = end - from;
+    while at < data.len() {
+      let stop = core::cmp::min(at + len, data.len());
+      out.push(Word::from_bytes(&data[at..stop]));
+      at = stop; len = lens.next().unwrap_or(WORD_LEN);
+    }
+    out
+  }
+  // *private* chunk lengths from the start of the data on, never ends
+  fn _lens(&self) -> impl Iterator<Item = usize> + '_ {
+    self.regions . iter() . flat_map(|(len, stride)| {
+      core::iter::repeat_n(*stride, len / stride) . chain((len % stride > 0) . then_some(len % stride))
+    })
+    . chain(self.strides . iter() . copied() . cycle())
+    . flat_map(_pieces)
+  }
+}
+
+impl Default for ViewConfig {
+  fn default() -> Self { Self::new() }
+}
+
+impl From<WithSig> for ViewConfig {
+  fn from(with_sig : WithSig) -> Self {
+    match with_sig {
+      WithSig::True  => Self::new(),
+      WithSig::False => Self::new().header(0)
+    }
   }
 }
 
+// strides are at least a byte
+fn _stride(stride : usize) -> usize { core::cmp::max(stride, ONE) }
+// a record as chunks, whole words then what's left
+fn _pieces(len : usize) -> impl Iterator<Item = usize> {
+  core::iter::repeat_n(WORD_LEN, len / WORD_LEN) . chain((!len . is_multiple_of(WORD_LEN)) . then_some(len % WORD_LEN))
+}
+
 /* ----------------------------------------------------------------------------
  View structure
 -----------------------------------------------------------------------------*/
@@ -240,44 +457,50 @@ impl PartialEq for Word {
   /* heavy, easy, general purpose. Foundational functionality can be
      extracted from Kwl32::util and Bai::con for more performance */
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct View {
-  sig  : Option<Signature>,
-  page : Vec<Word>
+  sig    : Option<Signature>,
+  page   : Vec<Word>,
+  abi    : Option<Function>,
+  mode   : EditMode,
+  config : ViewConfig,
+  // notes on words by index, what `Render` prints beside them
+  notes  : BTreeMap<usize, String>
 }
 
 pub enum WithSig { True, False }
 
+// Raw leaves every word alone. Fixup keeps offset and length words true when
+// words are appended, removed or inserted, through the bound abi or inferred
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditMode { Raw, Fixup }
+
+// explicit unit returns are house style
+#[allow(clippy::unused_unit)]
 impl View {
 
   pub fn new(call : Calldata, with_sig : WithSig) -> Self {
-
-    let min  = |x,y| std::cmp::min(x,y);
-    let mins = |   | min(SIG_LEN, call.data.len());  // handle missuse of sig
-
-     // chunk byte array
-    let chunks = | bytes: &[u8], step: usize | -> Vec<Word> {
-      (0..bytes.len()) . step_by(step) . map(|x| {
-        Some(Word::from_bytes(&bytes[x..min(x + step, bytes.len())]))
-        . unwrap_or(Word::from_bytes(&EMPTY_BYTES32))
-      })
-      . collect::<Vec<Word>>()
-    };
-
-    // no point forcing a shift trick in here just match
-    match with_sig {
-      WithSig::True  => View {
-        sig  : Some(Signature::from_bytes(&call.data.bytes()[..mins()])),
-        page : Some(chunks(&call.data.bytes()[mins()..], WORD_LEN))
-             . unwrap_or(chunks(&EMPTY_BYTES32, WORD_LEN))
+    Self::with_config(call, ViewConfig::from(with_sig))
+  }
+  // view with a configured header length and chunk strides
+  pub fn with_config(call : Calldata, config : ViewConfig) -> Self {
+    let bytes = call.data.bytes();
+    let head  = core::cmp::min(config.header, bytes.len());  // handle missuse of sig
+    View {
+      sig    : match config.header {
+        ZERO_INDEX => None,
+        SIG_LEN    => Some(Signature::from_bytes(&bytes[..head])),
+        _          => Some(Signature::from_header(&bytes[..head]))
       },
-      WithSig::False => View {
-        sig  : None,
-        page : Some(chunks(&call.data.bytes(), WORD_LEN))
-             . unwrap_or(chunks(&EMPTY_BYTES32, WORD_LEN))
-      }
+      page   : config.chunks(&bytes[head..], ZERO_INDEX),
+      abi    : None,
+      mode   : EditMode::Raw,
+      config,
+      notes  : BTreeMap::new()
     }
   }
+  // returns the config the view was chunked with
+  pub fn config(&self) -> &ViewConfig { &self.config }
 
 /* ----------------------------------------------------------------------------
 View cont..                 common functionality
@@ -289,12 +512,28 @@ View cont..                 common functionality
   // returns the 4 byte function signature as a hex str
   pub fn sig(&self) -> String {
     if let Some(x) = &self.sig {
-      bytes_to_hex(&x.data.bytes())
+      bytes_to_hex(x.data.bytes())
     } else { String::from("") }
   }
+  // returns the raw bytes of the sig, empty without one
+  pub fn sig_bytes(&self) -> &[u8] {
+    self.sig . as_ref() . map(|x| x.bytes()) . unwrap_or(&EMPTY_U8_SLICE)
+  }
+  // returns every known text signature for the 4 byte sig, collisions included
+  #[cfg(feature = "std")]
+  pub fn sig_candidates(&self) -> Vec<String> {
+    self.sig_candidates_in(Registry::bundled_ref())
+  }
+  // as above, resolved through a supplied registry
+  pub fn sig_candidates_in(&self, registry : &Registry) -> Vec<String> {
+    match &self.sig {
+      Some(x) => registry.lookup(x.bytes()).to_vec(),
+      None    => Vec::new()
+    }
+  }
   // returns the `arguments` portion of the calldata
   pub fn data(&self) -> String {
-    if &self.word_count() <= &ONE_WORD {
+    if self.word_count() == ONE_WORD {
       self.word(0)
     } else { self.page().join("") }
   }
@@ -306,6 +545,15 @@ View cont..                 common functionality
   pub fn hex_0x(&self) -> String {
     "0x".to_owned() + &self.sig() + &self.data()
   }
+  // returns the raw bytes of the `arguments` portion
+  pub fn data_bytes(&self) -> Vec<u8> {
+    self.page . iter() . flat_map(|x| x.bytes().to_vec()) . collect()
+  }
+  // returns the raw bytes of the complete calldata
+  pub fn bytes(&self) -> Vec<u8> {
+    let sig = self.sig . as_ref() . map(|x| x.bytes()) . unwrap_or(&EMPTY_U8_SLICE);
+    [sig, &self.data_bytes()].concat()
+  }
   // returns all 32 byte words as hex str's, in an array
   pub fn page(&self) -> Vec<String> {
     self.words(ZERO_INDEX, self.word_count())
@@ -323,16 +571,353 @@ View cont..                 common functionality
   }
   // returns the number of word segments in array
   pub fn word_count(&self) -> usize { self.page.len() }
-  // quick prints a summary
+  // quick prints a summary, see `Render` for the options
+  #[cfg(feature = "std")]
   pub fn summary(&self) {
-    if self.word_count() > SUMMARY_COUNT {
-      print!("Sig: {}\nData:\n{}\nView:\n{:?}\nCount: {}\n",
-      self.sig(), self.data(), self.page(), self.word_count()) 
-    } else {
-      print!("Sig: {}\nData:  {}\nView:  {:?}\nCount: {}\n",
-      self.sig(), self.data(), self.page(), self.word_count()) 
+    print!("{}", Render::new().render(self))
+  }
+  // classic hexdump of the complete calldata, words marked
+  pub fn hexdump(&self) -> String {
+    Hexdump::new().word_marks(true).dump_view(self)
+  }
+  // note a word, printed beside it in place of its slot name. Notes stay
+  // with the index, edits that move words don't move them
+  pub fn annotate(&mut self, index : usize, note : &str) -> () {
+    self.notes.insert(index, note.to_owned());
+  }
+  // returns the note on a word, if any
+  pub fn note(&self, index : usize) -> Option<&str> {
+    self.notes . get(&index) . map(|x| x.as_str())
+  }
+
+/* ----------------------------------------------------------------------------
+View cont..                 abi binding
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* bind a function and its parameter names stand in for slot indices */
+
+  // bind a function, its inputs describe the page
+  pub fn bind(&mut self, function : Function) -> () {
+    self.abi = Some(function)
+  }
+  // bind from a human readable declaration, names and all
+  pub fn bind_sig(&mut self, declaration : &str) -> Result<(), AbiError> {
+    Function::parse(declaration) . map(|f| self.bind(f))
+  }
+  // returns the bound function, if any
+  pub fn function(&self) -> Option<&Function> {
+    self.abi.as_ref()
+  }
+  // returns the word a named top level parameter's head starts, if one does
+  pub fn slot(&self, name : &str) -> Option<usize> {
+    let at = self._slots() . into_iter() . find(|(n, _)| n == name) . map(|(_, at)| at)?;
+    self._offsets() . iter() . position(|o| *o == at)
+  }
+  // returns the name of the parameter whose head starts at a word
+  pub fn slot_name(&self, index : usize) -> Option<String> {
+    let at = *self._offsets() . get(index)?;
+    self._slots() . into_iter() . find(|(_, o)| *o == at) . map(|(n, _)| n)
+  }
+  // decode the page against the bound function, names included
+  pub fn decode(&self) -> Result<Decoded, AbiError> {
+    let function = self.abi.as_ref()
+      . ok_or_else(|| AbiError::Mismatch("view has no bound function".to_owned()))?;
+    match &self.sig {
+      Some(_) => function.decode_input(&self.bytes()),
+      None    => Ok(Decoded {
+        name   : function.name.clone(),
+        fields : abi::decode_params(&function.inputs, &self.data_bytes())?
+      })
+    }
+  }
+  // returns the value at a named path, `amountOutMin`, `order.maker`, `path[1]`
+  pub fn get(&self, path : &str) -> Result<Value, AbiError> {
+    let (kind, at) = self._locate(path)?;
+    abi::decode_value(&kind, &self.data_bytes(), at)
+  }
+  // replace the value at a named path, rejects a value the slot can't hold.
+  // value types only, dynamic values change the tail's shape, see `set_bytes`
+  pub fn set(&mut self, path : &str, value : Value) -> Result<(), AbiError> {
+    let (kind, at) = self._locate(path)?;
+    let word = value.encode_word(&kind)?;
+    self._write_at(at, &word)
+  }
+  // as above, parsing text for the slot's type. decimal or 0x numbers
+  pub fn set_str(&mut self, path : &str, text : &str) -> Result<(), AbiError> {
+    let (kind, _) = self._locate(path)?;
+    self.set(path, Value::parse(&kind, text)?)
+  }
+  // zero the value at a named path
+  pub fn clear_named(&mut self, path : &str) -> Result<(), AbiError> {
+    let (kind, at) = self._locate(path)?;
+    match kind.is_dynamic() || kind.head_len() != WORD_LEN {
+      true  => Err(AbiError::Type(format!("{} is not a single word", path))),
+      false => self._write_at(at, &EMPTY_BYTES32)
+    }
+  }
+  // append an element to the dynamic array at a named path, the tail grows
+  // and the offsets after it move
+  pub fn push_array_element(&mut self, path : &str, value : Value) -> Result<(), AbiError> {
+    let (kind, mut items) = self._array(path)?;
+    if !value.fits(&kind) {
+      return Err(AbiError::Type(format!("{} can't hold {}", kind.canonical(), value)))
+    }
+    items.push(value);
+    self._reencode(path, Value::Array(items))
+  }
+  // remove an element from the dynamic array at a named path, returns it
+  pub fn remove_array_element(&mut self, path : &str, index : usize) -> Result<Value, AbiError> {
+    let (_, mut items) = self._array(path)?;
+    if index >= items.len() { return Err(AbiError::OutOfBounds(index)) }
+    let removed = items.remove(index);
+    self._reencode(path, Value::Array(items)) . map(|_| removed)
+  }
+  // replace the bytes or string at a named path, padded out to a word
+  pub fn set_bytes(&mut self, path : &str, payload : &[u8]) -> Result<(), AbiError> {
+    let value = match self._locate(path)?.0 {
+      ParamType::Bytes  => Value::Bytes(payload.to_vec()),
+      ParamType::String => Value::String(
+        String::from_utf8(payload.to_vec()) . map_err(|_| AbiError::Type(format!("{} is not utf8", path)))?
+      ),
+      _ => return Err(AbiError::Type(format!("{} is not bytes or string", path)))
+    };
+    self._reencode(path, value)
+  }
+  // *private* element type and current elements of a dynamic array
+  fn _array(&self, path : &str) -> Result<(ParamType, Vec<Value>), AbiError> {
+    match (self._locate(path)?.0, self.get(path)?) {
+      (ParamType::Array(t), Value::Array(items)) => Ok((*t, items)),
+      _ => Err(AbiError::Type(format!("{} is not a dynamic array", path)))
     }
   }
+  // *private* swap the encoding of the dynamic value at a path for a new one,
+  // moving what follows and rewriting the offsets that point past it. The
+  // old value is taken to be in the standard layout
+  fn _reencode(&mut self, path : &str, value : Value) -> Result<(), AbiError> {
+    let (kind, at) = self._locate(path)?;
+    let data = self.data_bytes();
+    let old  = abi::encode_value(&kind, &abi::decode_value(&kind, &data, at)?)?.len();
+    let end  = core::cmp::min(at + old, data.len());
+    let new  = abi::encode_value(&kind, &value)?;
+    let function = self.abi.as_ref()
+      . ok_or_else(|| AbiError::Mismatch("view has no bound function".to_owned()))?;
+    // links inside the value are rewritten by its new encoding
+    let links = abi::links(&function.inputs, &data)? . into_iter()
+      . filter(|l| l.at() < at || l.at() >= end)
+      . collect::<Vec<_>>();
+    // in padded words, a short or unpadded old tail still took its last word
+    let delta = new.len() . div_ceil(WORD_LEN) as isize - (end - at) . div_ceil(WORD_LEN) as isize;
+    let mut bytes = [&data[..at], &new, &data[end..]].concat(); let len = bytes.len();
+    abi::relink(&links, at + core::cmp::min(end - at, new.len()), delta) . into_iter()
+    . filter(|(p, _)| p + WORD_LEN <= len)
+    . for_each(|(p, v)| bytes[p..p + WORD_LEN] . copy_from_slice(&pad32l(&(v as u64).to_be_bytes())));
+    self._rechunk(ZERO_INDEX, &bytes);
+    Ok(())
+  }
+  // *private* type and data offset of a named path
+  fn _locate(&self, path : &str) -> Result<(abi::ParamType, usize), AbiError> {
+    let function = self.abi.as_ref()
+      . ok_or_else(|| AbiError::Mismatch("view has no bound function".to_owned()))?;
+    abi::locate(&function.inputs, &self.data_bytes(), path)
+  }
+  // *private* write a whole abi word at a data offset, must sit on a word.
+  // Bytes, not page words, so any header or stride lands it right
+  fn _write_at(&mut self, at : usize, word : &[u8;WORD_LEN]) -> Result<(), AbiError> {
+    match at . is_multiple_of(WORD_LEN) && at + WORD_LEN <= self._offset_of(self.page.len()) {
+      true  => { self.write_bytes(at, word); Ok(()) },
+      false => Err(AbiError::OutOfBounds(at))
+    }
+  }
+  // *private* names with the data offset their head starts at
+  fn _slots(&self) -> Vec<(String, usize)> {
+    let mut at = ZERO_INDEX;
+    self.abi . iter() . flat_map(|f| f.inputs.iter()) . map(|p| {
+      let start = at; at = at . saturating_add(p.kind.head_len()); (p.name.clone(), start)
+    })
+    . collect()
+  }
+
+/* ----------------------------------------------------------------------------
+View cont..                 iteration
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* walk the page without the hex allocations of `page`. Everything yields
+     the word index and the byte offset it starts at, counted from the first
+     byte after the sig, alongside the word or words */
+
+  pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &Word)> + '_ {
+    self._offsets() . into_iter() . zip(self.page.iter()) . enumerate() . map(|(i, (o, w))| (i, o, w))
+  }
+  // as above, words can be changed in place. Offsets are as the walk began
+  pub fn iter_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut Word)> + '_ {
+    self._offsets() . into_iter() . zip(self.page.iter_mut()) . enumerate() . map(|(i, (o, w))| (i, o, w))
+  }
+  // overlapping runs of `n` words, a run is at least one
+  pub fn windows(&self, n : usize) -> impl Iterator<Item = (usize, usize, &[Word])> + '_ {
+    let offsets = self._offsets();
+    self.page . windows(core::cmp::max(n, ONE)) . enumerate() . map(move |(i, w)| (i, offsets[i], w))
+  }
+  // runs of `n` words side by side, the last may be short
+  pub fn chunks_of(&self, n : usize) -> impl Iterator<Item = (usize, usize, &[Word])> + '_ {
+    let n = core::cmp::max(n, ONE); let offsets = self._offsets();
+    self.page . chunks(n) . enumerate() . map(move |(i, w)| (i * n, offsets[i * n], w))
+  }
+  // words with any bit set
+  pub fn nonzero(&self) -> impl Iterator<Item = (usize, usize, &Word)> + '_ {
+    self.iter() . filter(|(_, _, w)| !w.is_zero())
+  }
+  // first word to match
+  pub fn find<F: FnMut(&Word) -> bool>(&self, mut f : F) -> Option<(usize, usize, &Word)> {
+    self.iter() . find(|(_, _, w)| f(w))
+  }
+  // *private* byte offset each word starts at
+  fn _offsets(&self) -> Vec<usize> {
+    self.page . iter() . scan(ZERO_INDEX, |at, w| { let o = *at; *at += w.len(); Some(o) }) . collect()
+  }
+  // *private* byte offset a word starts at, the data length past the end
+  fn _offset_of(&self, index : usize) -> usize {
+    self.page[..core::cmp::min(index, self.page.len())] . iter() . map(|w| w.len()) . sum()
+  }
+
+/* ----------------------------------------------------------------------------
+View cont..                 edit mode
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* growing or shrinking the tail moves everything an offset points past.
+     In Fixup mode structural edits rewrite those offsets, and the length of
+     an array or bytes they land in, so the result still decodes */
+
+  pub fn set_edit_mode(&mut self, mode : EditMode) -> () {
+    self.mode = mode
+  }
+
+  pub fn edit_mode(&self) -> EditMode { self.mode }
+
+  // offset and length words in the page, from the bound function when it
+  // decodes, inferred from the data otherwise
+  pub fn links(&self) -> Vec<abi::Link> {
+    let data = self.data_bytes();
+    self.abi . as_ref()
+    . and_then(|f| abi::links(&f.inputs, &data) . ok())
+    . unwrap_or_else(|| abi::infer_links(&data))
+  }
+  // *private* run a structural edit at data offset `at`, rewriting the links
+  // around it when in Fixup mode. Links count bytes, so the edit is sized by
+  // what it did to the data. One that isn't whole words on a word boundary
+  // has nothing to relink by, whatever the config
+  fn _structural<T>(&mut self, at : usize, edit : impl FnOnce(&mut Self) -> T) -> T {
+    let links  = match self.mode { EditMode::Raw => Vec::new(), EditMode::Fixup => self.links() };
+    let before = self._offset_of(self.page.len());
+    let out    = edit(self);
+    let after  = self._offset_of(self.page.len());
+    let change = after . abs_diff(before);
+    if !links.is_empty() && at . is_multiple_of(WORD_LEN) && change . is_multiple_of(WORD_LEN) {
+      let delta = (change / WORD_LEN) as isize * if after < before { -1 } else { 1 };
+      abi::relink(&links, at, delta) . into_iter()
+      . filter(|(p, _)| p + WORD_LEN <= after)
+      . for_each(|(p, value)| self.write_bytes(p, &pad32l(&(value as u64).to_be_bytes())));
+    }
+    out
+  }
+
+/* ----------------------------------------------------------------------------
+View cont..                 byte addressing
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* packed and misaligned fields don't respect words. Offsets here count
+     from the first byte after the sig, reads and writes trunc to the end */
+
+  // returns `len` bytes from a byte offset, across word boundaries
+  pub fn read_bytes(&self, offset : usize, len : usize) -> Vec<u8> {
+    let data  = self.data_bytes();
+    let start = core::cmp::min(offset, data.len());
+    data[start..core::cmp::min(offset . saturating_add(len), data.len())].to_vec()
+  }
+  // overwrite bytes from a byte offset, words keep their lengths
+  pub fn write_bytes(&mut self, offset : usize, bytes : &[u8]) -> () {
+    let (mut id, mut within) = self._byte_pos(offset); let mut src = bytes;
+    while !src.is_empty() && id < self.page.len() {
+      let mut word = self.page[id].bytes().to_vec();
+      let n = core::cmp::min(word.len() - within, src.len());
+      word[within..within + n] . copy_from_slice(&src[..n]);
+      self.page[id] = Word::from_bytes(&word);
+      src = &src[n..]; id += ONE; within = ZERO_INDEX;
+    }
+  }
+  // insert bytes at a byte offset, words from there on are re-chunked.
+  // whole words on a word boundary move what follows by words, so Fixup applies
+  pub fn insert_bytes(&mut self, offset : usize, bytes : &[u8]) -> () {
+    if bytes.is_empty() { return }
+    let (id, within) = self._byte_pos(offset);
+    let tail = self._tail_bytes(id);
+    let data = [&tail[..within], bytes, &tail[within..]].concat();
+    self._structural(self._offset_of(id) + within, |v| v._rechunk(id, &data))
+  }
+  // delete `len` bytes from a byte offset, words from there on are re-chunked.
+  // whole words on a word boundary move what follows by words, so Fixup applies
+  pub fn delete_bytes(&mut self, offset : usize, len : usize) -> () {
+    let (id, within) = self._byte_pos(offset);
+    let tail  = self._tail_bytes(id);
+    let end   = core::cmp::min(within . saturating_add(len), tail.len());
+    let start = core::cmp::min(within, end);
+    if start == end { return }
+    let data  = [&tail[..start], &tail[end..]].concat();
+    self._structural(self._offset_of(id) + start, |v| v._rechunk(id, &data))
+  }
+  // *private* word holding a byte offset and the offset within it. Past the
+  // end is the end of a short last word, or the end of the page
+  fn _byte_pos(&self, offset : usize) -> (usize, usize) {
+    let mut start = ZERO_INDEX;
+    for (id, word) in self.page . iter() . enumerate() {
+      if offset < start + word.len() { return (id, offset - start) }
+      start += word.len();
+    }
+    match self.page.last() {
+      Some(x) if x.len() < WORD_LEN => (self.page.len() - ONE, x.len()),
+      _                             => (self.page.len(), ZERO_INDEX)
+    }
+  }
+  // *private* are the words in a range all a full 32 bytes
+  fn _full_words(&self, start : usize, end : usize) -> bool {
+    end <= self.page.len() && self.page[start..end] . iter() . all(|x| x.len() == WORD_LEN)
+  }
+  // *private* flat bytes of the words from an index on
+  fn _tail_bytes(&self, id : usize) -> Vec<u8> {
+    self.page[id..] . iter() . flat_map(|x| x.bytes().to_vec()) . collect()
+  }
+  // *private* replace the words from an index on with new bytes, chunked
+  fn _rechunk(&mut self, id : usize, bytes : &[u8]) -> () {
+    let from = self.page[..id] . iter() . map(|x| x.len()) . sum();
+    self.page.truncate(id);
+    self.page . extend(self.config.chunks(bytes, from))
+  }
+
+/* ----------------------------------------------------------------------------
+View cont..                 value search
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* where an address or amount turns up, as a padded word, as loose bytes
+     or inside a `bytes` payload, see `search`. Words here are abi words,
+     32 bytes from the first byte after the sig, whatever the config */
+
+  // every occurrence of `value`, in order of offset
+  pub fn find_value(&self, value : &[u8]) -> Vec<Found> {
+    search::find_value(&self.data_bytes(), &self.links(), value)
+  }
+  // write `new` over every occurrence of `old`, each padded the way it was
+  // found. Returns the count, or changes nothing if one doesn't fit
+  pub fn replace_all(&mut self, old : &[u8], new : &[u8]) -> Result<usize, KawalaError> {
+    let patches = self.find_value(old) . iter()
+      . map(|f| f.patch(old, new) . map(|bytes| (f.offset(), bytes)))
+      . collect::<Result<Vec<_>, _>>()?;
+    patches . iter() . for_each(|(offset, bytes)| self.write_bytes(*offset, bytes));
+    Ok(patches.len())
+  }
 
 /* ----------------------------------------------------------------------------
 View cont..      destructive functions that mutate state
@@ -347,10 +932,15 @@ View cont..      destructive functions that mutate state
   pub fn replace_sig(&mut self, string : &str) -> () {
     self.sig = Some (Signature::from_hex(string))
   }
+  // replace a configured header of any length, `replace_sig` keeps to 4 bytes
+  pub fn replace_header(&mut self, bytes : &[u8]) -> () {
+    self.sig = Some (Signature::from_header(bytes))
+  }
   // *private* replaces a word, will replace last if pass out of bounds
   fn _replace_word(&mut self, index : usize, bytes : &[u8]) -> () {
-    let slice_cap = std::cmp::min(WORD_LEN, bytes.len());
-    let capped_id = std::cmp::min(index, &self.word_count() -ZERO_OFFSET);
+    if self.page.is_empty() { return }
+    let slice_cap = core::cmp::min(WORD_LEN, bytes.len());
+    let capped_id = core::cmp::min(index, self.word_count() -ZERO_OFFSET);
     let slice     = &bytes[..slice_cap];
     self.page[capped_id] = Word::from_bytes(slice)
   }
@@ -386,41 +976,61 @@ View cont..      destructive functions that mutate state
   pub fn remove(&mut self, index : usize) -> String {
     self.__remove(index).hex()
   }
+  // insert a word before index using a byte array as source, appends past the end
+  pub fn insert_from_bytes(&mut self, index : usize, bytes : &[u8]) -> () {
+    self.__insert(index, Word::from_bytes(bytes))
+  }
+  // insert a word before index using a hex as source
+  pub fn insert(&mut self, index : usize, string : &str) -> () {
+    self.__insert(index, Word::from_hex(string))
+  }
+  // pop the first item, return the result of its hex method
+  pub fn pop_front(&mut self) -> String {
+    self.__pop_front().hex()
+  }
+  // swap a range of words for others from hex, returns the removed as hex
+  pub fn splice(&mut self, range : core::ops::Range<usize>, strings : &[&str]) -> Vec<String> {
+    let words = strings . iter() . map(|x| Word::from_hex(x)) . collect::<Vec<Word>>();
+    self.__splice(range, &words) . iter() . map(|x| x.hex()) . collect()
+  }
+  // remove a range of words, returned as hex. Trunc to max
+  pub fn drain(&mut self, range : core::ops::Range<usize>) -> Vec<String> {
+    self.__drain(range) . iter() . map(|x| x.hex()) . collect()
+  }
   // replace a word with a word, consume replacement
   pub fn replace_with(&mut self, to : usize, from : usize) -> () {
-    let word = Some(self.page.remove(self._id(from))) 
-    . unwrap_or(Word::from_bytes(&EMPTY_BYTES32));
-    self._replace_word(self._id(to), &word.bytes())
+    let word = self.__remove(from);
+    self._replace_word(self._id(to), word.bytes())
   }
   // replace word with left padded equivalent
   pub fn left_pad(&mut self, index : usize) -> () {
     let word = self.__word(index);
-    self.replace_from_bytes(index, &pad32l(word.data.bytes()))
+    self.replace_from_bytes(index, &pad32l(word.bytes()))
   }
   // replace word with right padded equivalent
   pub fn right_pad(&mut self, index : usize) -> (){
     let word = self.__word(index);
-    self.replace_from_bytes(index, &pad32r(word.data.bytes()))
+    self.replace_from_bytes(index, &pad32r(word.bytes()))
   }
   // replace with merge no overlap
   pub fn xor_into(&mut self, index: usize, array : &[u8]) -> () {
     let _a = Word::from_bytes(array); let _b = self.__word(index);
-    self.replace_from_bytes(index, &self.__xor_words((&_a, _b)).bytes())
+    self.replace_from_bytes(index, self.__xor_words((&_a, _b)).bytes())
   }
   // replace with difference removed
   pub fn and_into(&mut self, index: usize, array : &[u8]) -> () {
     let _a = Word::from_bytes(array); let _b = self.__word(index);
-    self.replace_from_bytes(index, &self.__and_words((&_a, _b)).bytes())
+    self.replace_from_bytes(index, self.__and_words((&_a, _b)).bytes())
   }
   // replace with merge and overlap
   pub fn or_into(&mut self, index: usize, array : &[u8]) -> () {
     let _a = Word::from_bytes(array); let _b = self.__word(index);
-    self.replace_from_bytes(index, &self.__or_words((&_a, _b)).bytes())
+    self.replace_from_bytes(index, self.__or_words((&_a, _b)).bytes())
   }
   // flip 'em, the bird.. and toggle all the bits
   pub fn not(&mut self, index: usize) -> () {
     let _a = self.__word(index);
-    self.replace_from_bytes(index, &self.__not_word(&_a).bytes())
+    self.replace_from_bytes(index, self.__not_word(_a).bytes())
   }
   // *private* perform xor on the 2 tail elements, consume the tail
   fn _fold (&mut self) -> () {
@@ -448,7 +1058,176 @@ View cont..      destructive functions that mutate state
   }
   // returns an index within bounds
   fn _id(&self, x: usize) -> usize {
-    let y = self.page.len() -ZERO_OFFSET; std::cmp::max(0, std::cmp::min(x, y))
+    let y = self.page.len() . saturating_sub(ZERO_OFFSET); core::cmp::min(x, y)
+  }
+
+/* ----------------------------------------------------------------------------
+View cont..                 checked, try_ variants
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* the plain methods clamp, an index past the end lands on the last word,
+     bad hex reads as zeros and long input is cut. They still do, checking is
+     what's opt in. The try_ variants check first and say what's wrong
+     instead of editing something else */
+
+  // returns a single word as hex, or why not
+  pub fn try_word(&self, index : usize) -> Result<String, KawalaError> {
+    self.__try_word(index) . map(|x| x.hex())
+  }
+  // returns a range of words as hex, the range must be in the page
+  pub fn try_words(&self, start : usize, end : usize) -> Result<Vec<String>, KawalaError> {
+    self.__try_words(start, end) . map(|x| x . iter() . map(|w| w.hex()) . collect())
+  }
+  // replace a word from hex, at most a word of it
+  pub fn try_replace(&mut self, index : usize, string : &str) -> Result<(), KawalaError> {
+    self.try_replace_from_bytes(index, &parse_hex(string)?)
+  }
+  // replace a word from bytes, at most a word of them
+  pub fn try_replace_from_bytes(&mut self, index : usize, bytes : &[u8]) -> Result<(), KawalaError> {
+    let word = Word::try_from_bytes(bytes)?;
+    self._check(index)?; self.page[index] = word; Ok(())
+  }
+  // replace the sig from hex, exactly 4 bytes
+  pub fn try_replace_sig(&mut self, string : &str) -> Result<(), KawalaError> {
+    self.sig = Some(Signature::try_from_hex(string)?); Ok(())
+  }
+  // replace the sig from bytes, exactly 4 of them
+  pub fn try_replace_sig_from_bytes(&mut self, bytes : &[u8]) -> Result<(), KawalaError> {
+    self.sig = Some(Signature::try_from_bytes(bytes)?); Ok(())
+  }
+  // append a word from hex, at most a word of it
+  pub fn try_append(&mut self, string : &str) -> Result<(), KawalaError> {
+    Word::try_from_hex(string) . map(|x| self.__append(x))
+  }
+  // append a word from bytes, at most a word of them
+  pub fn try_append_from_bytes(&mut self, bytes : &[u8]) -> Result<(), KawalaError> {
+    Word::try_from_bytes(bytes) . map(|x| self.__append(x))
+  }
+  // zero a word
+  pub fn try_clear(&mut self, index : usize) -> Result<(), KawalaError> {
+    self._check(index)?; self.clear(index); Ok(())
+  }
+  // pop the last word as hex, an empty page is an error
+  pub fn try_pop(&mut self) -> Result<String, KawalaError> {
+    self._check_empty()?; Ok(self.pop())
+  }
+  // pop the first word as hex, an empty page is an error
+  pub fn try_pop_front(&mut self) -> Result<String, KawalaError> {
+    self._check_empty()?; Ok(self.pop_front())
+  }
+  // remove a word, returned as hex
+  pub fn try_remove(&mut self, index : usize) -> Result<String, KawalaError> {
+    self.__try_remove(index) . map(|x| x.hex())
+  }
+  // replace a word with a word, consume replacement. Both must exist, past
+  // that it does exactly what `replace_with` does
+  pub fn try_replace_with(&mut self, to : usize, from : usize) -> Result<(), KawalaError> {
+    self._check(to)?; self._check(from)?;
+    self.replace_with(to, from); Ok(())
+  }
+  // insert a word from hex before index, the end is fine
+  pub fn try_insert(&mut self, index : usize, string : &str) -> Result<(), KawalaError> {
+    self.try_insert_from_bytes(index, &parse_hex(string)?)
+  }
+  // insert a word from bytes before index, the end is fine
+  pub fn try_insert_from_bytes(&mut self, index : usize, bytes : &[u8]) -> Result<(), KawalaError> {
+    let word = Word::try_from_bytes(bytes)?;
+    if index > self.page.len() { return Err(KawalaError::OutOfBounds { index, len : self.page.len() }) }
+    self.__insert(index, word); Ok(())
+  }
+  // remove a range of words, returned as hex
+  pub fn try_drain(&mut self, range : core::ops::Range<usize>) -> Result<Vec<String>, KawalaError> {
+    self._check_range(&range)?; Ok(self.drain(range))
+  }
+  // swap a range of words for others from hex, returns the removed as hex
+  pub fn try_splice(&mut self, range : core::ops::Range<usize>, strings : &[&str]) -> Result<Vec<String>, KawalaError> {
+    self._check_range(&range)?;
+    let words = strings . iter() . map(|x| Word::try_from_hex(x)) . collect::<Result<Vec<Word>, _>>()?;
+    Ok(self.__splice(range, &words) . iter() . map(|x| x.hex()) . collect())
+  }
+  // the bit ops on a word that must exist, arrays at most a word
+  pub fn try_xor_into(&mut self, index : usize, array : &[u8]) -> Result<(), KawalaError> {
+    self._check_op(index, array)?; self.xor_into(index, array); Ok(())
+  }
+
+  pub fn try_and_into(&mut self, index : usize, array : &[u8]) -> Result<(), KawalaError> {
+    self._check_op(index, array)?; self.and_into(index, array); Ok(())
+  }
+
+  pub fn try_or_into(&mut self, index : usize, array : &[u8]) -> Result<(), KawalaError> {
+    self._check_op(index, array)?; self.or_into(index, array); Ok(())
+  }
+
+  pub fn try_not(&mut self, index : usize) -> Result<(), KawalaError> {
+    self._check(index)?; self.not(index); Ok(())
+  }
+
+  pub fn try_left_pad(&mut self, index : usize) -> Result<(), KawalaError> {
+    self._check(index)?; self.left_pad(index); Ok(())
+  }
+
+  pub fn try_right_pad(&mut self, index : usize) -> Result<(), KawalaError> {
+    self._check(index)?; self.right_pad(index); Ok(())
+  }
+
+  pub fn try_right_shift(&mut self, index : usize, shift : usize) -> Result<(), KawalaError> {
+    self._check(index)?; self.right_shift(index, shift); Ok(())
+  }
+
+  pub fn try_left_shift(&mut self, index : usize, shift : usize) -> Result<(), KawalaError> {
+    self._check(index)?; self.left_shift(index, shift); Ok(())
+  }
+  // byte addressed, the whole span must be in the data
+  pub fn try_read_bytes(&self, offset : usize, len : usize) -> Result<Vec<u8>, KawalaError> {
+    self._check_bytes(offset, len)?; Ok(self.read_bytes(offset, len))
+  }
+
+  pub fn try_write_bytes(&mut self, offset : usize, bytes : &[u8]) -> Result<(), KawalaError> {
+    self._check_bytes(offset, bytes.len())?; self.write_bytes(offset, bytes); Ok(())
+  }
+  // the end of the data is fine to insert at
+  pub fn try_insert_bytes(&mut self, offset : usize, bytes : &[u8]) -> Result<(), KawalaError> {
+    self._check_bytes(offset, 0)?; self.insert_bytes(offset, bytes); Ok(())
+  }
+
+  pub fn try_delete_bytes(&mut self, offset : usize, len : usize) -> Result<(), KawalaError> {
+    self._check_bytes(offset, len)?; self.delete_bytes(offset, len); Ok(())
+  }
+  // *private* the index of a word that exists
+  fn _check(&self, index : usize) -> Result<usize, KawalaError> {
+    match index < self.page.len() {
+      true  => Ok(index),
+      false => Err(KawalaError::OutOfBounds { index, len : self.page.len() })
+    }
+  }
+  // *private* something to take
+  fn _check_empty(&self) -> Result<(), KawalaError> {
+    match self.page.is_empty() { true => Err(KawalaError::Empty), false => Ok(()) }
+  }
+  // *private* a range inside the page, empty at the end is fine
+  fn _check_range(&self, range : &core::ops::Range<usize>) -> Result<(), KawalaError> {
+    if range.start > range.end { return Err(KawalaError::BadRange { start : range.start, end : range.end }) }
+    match range.end <= self.page.len() {
+      true  => Ok(()),
+      false => Err(KawalaError::OutOfBounds { index : range.end, len : self.page.len() })
+    }
+  }
+  // *private* a word that exists and an operand that fits it
+  fn _check_op(&self, index : usize, array : &[u8]) -> Result<(), KawalaError> {
+    self._check(index)?;
+    match array.len() <= WORD_LEN {
+      true  => Ok(()),
+      false => Err(KawalaError::TooLong { max : WORD_LEN, found : array.len() })
+    }
+  }
+  // *private* a byte span inside the data
+  fn _check_bytes(&self, offset : usize, len : usize) -> Result<(), KawalaError> {
+    let total = self.page . iter() . map(|x| x.len()) . sum::<usize>();
+    match offset . checked_add(len) . is_some_and(|end| end <= total) {
+      true  => Ok(()),
+      false => Err(KawalaError::ByteOutOfBounds { offset : offset . saturating_add(len), len : total })
+    }
   }
 
 /* ----------------------------------------------------------------------------
@@ -458,37 +1237,87 @@ View cont..   exposed functions that take or return Kawala types
 
   // append a word to the end
   pub fn __append(&mut self, word : Word) -> () {
-    self.page.push(word)
+    self._structural(self._offset_of(self.page.len()), |v| v.page.push(word))
   }
   // pop a word from the end
   pub fn __pop(&mut self) -> Word {
-    self.page.pop() . unwrap_or(Word::from_bytes(&EMPTY_BYTES32))
+    if self.page.is_empty() { return Word::from_bytes(&EMPTY_BYTES32) }
+    self._structural(self._offset_of(self.page.len() - ONE), |v| v.page.pop())
+    . unwrap_or(Word::from_bytes(&EMPTY_BYTES32))
   }
   // remove a word, remove last if out of bounds
   pub fn __remove(&mut self, index : usize) -> Word {
-    Some(self.page.remove(self._id(index))) 
-    . unwrap_or(Word::from_bytes(&EMPTY_BYTES32))
+    if self.page.is_empty() { return Word::from_bytes(&EMPTY_BYTES32) }
+    let id = self._id(index);
+    self._structural(self._offset_of(id), |v| v.page.remove(id))
+  }
+  // insert a word before index, append if out of bounds
+  pub fn __insert(&mut self, index : usize, word : Word) -> () {
+    let id = core::cmp::min(index, self.page.len());
+    self._structural(self._offset_of(id), |v| v.page.insert(id, word))
+  }
+  // pop a word from the front
+  pub fn __pop_front(&mut self) -> Word {
+    if self.page.is_empty() { return Word::from_bytes(&EMPTY_BYTES32) }
+    self.__remove(ZERO_INDEX)
+  }
+  // remove a range of words and return them, trunc to max
+  pub fn __drain(&mut self, range : core::ops::Range<usize>) -> Vec<Word> {
+    let end   = core::cmp::min(range.end, self.page.len());
+    let start = core::cmp::min(range.start, end);
+    if start == end { return Vec::new() }
+    self._structural(self._offset_of(start), |v| v.page.drain(start..end) . collect())
+  }
+  // swap a range of words for copies of others, return the removed
+  pub fn __splice(&mut self, range : core::ops::Range<usize>, words : &[Word]) -> Vec<Word> {
+    let start   = core::cmp::min(range.start, self.page.len());
+    let removed = self.__drain(range);
+    if !words.is_empty() {
+      let copies = words . iter() . map(|x| Word::from_bytes(x.bytes())) . collect::<Vec<Word>>();
+      self._structural(self._offset_of(start), |v| { v.page.splice(start..start, copies); })
+    }
+    removed
   }
 
 /*
-  To do: Profile pseudo deque vs `VecDeque` refactor. Consider how infrequent.
+  Front ops stay on `Vec`. `tests/page_profile.rs` times a word off the front
+  and onto the back in a release build. `Vec` takes 14ns at 8 words, 29ns at
+  64, 170ns at 512 and 3.7us at 4096, `VecDeque` a flat 6-7ns. But `__page`,
+  `__words`, `windows` and `chunks_of` lend `&[Word]` from `&self`, so a deque
+  would need `make_contiguous` after every edit, and with it the same round
+  costs 26ns, 46ns, 201ns and 3.7us. A deque only pays if those slices go,
+  and pages are calldata sized with front ops the rare case.
                                                                               */
 
+  // as `__remove`, an index past the end is an error
+  pub fn __try_remove(&mut self, index : usize) -> Result<Word, KawalaError> {
+    self._check(index)?; Ok(self.__remove(index))
+  }
+
 /* -----------------------*NOTE*: end of destructive ------------------------ */
 
   // returns a ref to all 32 byte Words
   pub fn __page(&self) -> &[Word] {
-    &self.__words(ZERO_INDEX, self.word_count())
+    self.__words(ZERO_INDEX, self.word_count())
   }
-  // returns a ref to a single Word, will truncate out of bounds to max len
+  // returns a ref to a single Word, will truncate out of bounds to max len.
+  // an empty page has no max, so an empty word stands in
   pub fn __word(&self, index : usize) -> &Word {
-    &self.page[std::cmp::min(index, &self.word_count() -ZERO_OFFSET)]
+    self.page . get(self._id(index)) . unwrap_or(&EMPTY_WORD)
+  }
+  // as `__word`, an index past the end is an error
+  pub fn __try_word(&self, index : usize) -> Result<&Word, KawalaError> {
+    self._check(index) . map(|i| &self.page[i])
+  }
+  // as `__words`, the range must be in the page
+  pub fn __try_words(&self, start : usize, end : usize) -> Result<&[Word], KawalaError> {
+    self._check_range(&(start..end)) . map(|_| &self.page[start..end])
   }
   // returns a ref to a range of Words, will trunc to max
   pub fn __words(&self, start : usize, end : usize) -> &[Word] {
     &self.page[
-      std::cmp::min(start, end)..
-      std::cmp::min(end, self.page.len())
+      core::cmp::min(start, end)..
+      core::cmp::min(end, self.page.len())
     ]
   }
   // roll bytes in word right a given number of shifts
@@ -518,13 +1347,122 @@ View cont..   exposed functions that take or return Kawala types
  // higher order abstraction to save some repetition
   pub fn _fops(
     &self,
-    f     : &dyn Fn(&[u8;WORD_LEN], &[u8;WORD_LEN]) -> [u8;WORD_LEN],
+    f     : &Op32,
     words : (&Word, &Word)
   ) -> Word {
     Word::from_bytes(&f(&words.0.as_bytes32(), &words.1.as_bytes32()))
   }
 }
 
+/* ----------------------------------------------------------------------------
+View cont..                 std traits
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* a view is its sig and its words. The bound abi, edit mode and config
+     describe how to work on it, so they don't take part in eq, hash or ord.
+     Words go by their bytes, length and all, where `Word` itself compares
+     padded. Views over different calldata are never equal */
+
+impl PartialEq for View {
+  fn eq(&self, other: &Self) -> bool {
+    self.sig == other.sig && _contents(self) . eq(_contents(other))
+  }
+}
+
+impl Eq for View {}
+
+impl core::hash::Hash for View {
+  fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+    self.sig.hash(state); self.page.len().hash(state);
+    _contents(self) . for_each(|x| x.hash(state))
+  }
+}
+
+impl PartialOrd for View {
+  fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for View {
+  fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+    self.sig.cmp(&other.sig) . then_with(|| _contents(self) . cmp(_contents(other)))
+  }
+}
+
+// *private* each word's own bytes, what eq, hash and ord go by
+fn _contents(view : &View) -> impl Iterator<Item = &[u8]> {
+  view.page . iter() . map(|w| w.bytes())
+}
+
+// hex calldata, sig first
+impl core::str::FromStr for View {
+  type Err = KawalaError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    View::try_from(Calldata::try_from_hex(s)?.bytes())
+  }
+}
+
+// raw calldata, sig first. Short of a sig is an error
+impl TryFrom<&[u8]> for View {
+  type Error = KawalaError;
+  fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+    match bytes.len() >= SIG_LEN {
+      true  => Ok(View::new(Calldata::from_bytes(bytes), WithSig::True)),
+      false => Err(KawalaError::Length { expected : SIG_LEN, found : bytes.len() })
+    }
+  }
+}
+
+// the default `Render`
+impl core::fmt::Display for View {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    Render::new().render_to(self, f)
+  }
+}
+
+// std indexing panics out of bounds, see `__word` to clamp or `__try_word`
+impl core::ops::Index<usize> for View {
+  type Output = Word;
+  fn index(&self, index: usize) -> &Word { &self.page[index] }
+}
+
+impl core::ops::IndexMut<usize> for View {
+  fn index_mut(&mut self, index: usize) -> &mut Word { &mut self.page[index] }
+}
+
+impl IntoIterator for View {
+  type Item     = Word;
+  type IntoIter = alloc::vec::IntoIter<Word>;
+  fn into_iter(self) -> Self::IntoIter { self.page.into_iter() }
+}
+
+impl<'a> IntoIterator for &'a View {
+  type Item     = &'a Word;
+  type IntoIter = core::slice::Iter<'a, Word>;
+  fn into_iter(self) -> Self::IntoIter { self.page.iter() }
+}
+
+impl<'a> IntoIterator for &'a mut View {
+  type Item     = &'a mut Word;
+  type IntoIter = core::slice::IterMut<'a, Word>;
+  fn into_iter(self) -> Self::IntoIter { self.page.iter_mut() }
+}
+
+// appends, each one a structural edit so Fixup applies
+impl Extend<Word> for View {
+  fn extend<I: IntoIterator<Item = Word>>(&mut self, words: I) {
+    words . into_iter() . for_each(|x| self.__append(x))
+  }
+}
+
+// a page of words with no sig
+impl FromIterator<Word> for View {
+  fn from_iter<I: IntoIterator<Item = Word>>(words: I) -> Self {
+    let mut view = View::new(Calldata::from_bytes(&EMPTY_U8_SLICE), WithSig::False);
+    view.page = words . into_iter() . collect(); view
+  }
+}
+
 /* ----------------------------------------------------------------------------
 Appendix
 -----------------------------------------------------------------------------*/
@@ -540,18 +1478,44 @@ const  EMPTY_BYTES32   :    [u8;32]       =   [0;32];
 const  ZERO_INDEX      :    usize         =   0;
 const  ZERO_OFFSET     :    usize         =   1;
 const  ONE_WORD        :    usize         =   1;
-const  SUMMARY_COUNT   :    usize         =   2;
 const  ONE             :    usize         =   1;
 const  MIN_FOLD        :    usize         =   2;
 
-// marshall through prefixed hex strings
+// a word op over two padded words, what `_fops` folds with
+type Op32 = dyn Fn(&[u8;WORD_LEN], &[u8;WORD_LEN]) -> [u8;WORD_LEN];
+
+static EMPTY_WORD      :    Word          =   Word { data : EMPTY_BYTES32, len : WORD_LEN as u8 };
+
+// marshall through prefixed hex strings, short or odd text can't slice past
 fn marshal_pre(fixed: &str) -> Vec<u8> {
-  let ost = &fixed[..2] == "0x"; hex_to_bytes(&fixed[shift(ost)..])
+  let ost = fixed.starts_with("0x"); hex_to_bytes(&fixed[shift(ost)..])
 }
 // just removes the need for an if to check hex, or multiplication of the bool.
-fn shift(b : bool) -> usize { ((b as u8) << (b as u8) << 0) as usize }
-
-/* mod imports */    pub mod bai; pub mod kwl32;
+fn shift(b : bool) -> usize { ((b as u8) << (b as u8)) as usize }
+
+/* mod imports */    pub mod bai; pub mod kwl32; pub mod kec256;
+/* sub systems */    pub mod json; pub mod registry; pub mod abi; pub mod log;
+                     pub mod revert; pub mod contract; pub mod diff;
+                     pub mod error; pub mod render; pub mod borrowed;
+                     pub mod builder; pub mod template;
+                     pub mod search;
+/* serde impls  */    #[cfg(feature = "serde")] mod serial;
+/* alloc prelude */  mod prelude {
+                       pub use alloc::{ borrow::ToOwned, boxed::Box, string::{ String, ToString }, vec::Vec };
+                     }
+use prelude::*;
+pub use registry::Registry;
+pub use log::Log;
+pub use contract::Contract;
+pub use error::KawalaError;
+pub use render::{ Render, Hexdump };
+pub use borrowed::ViewRef;
+pub use builder::CalldataBuilder;
+pub use template::{ Template, Templates };
+pub use search::Found;
+use alloc::collections::BTreeMap;
+use error::parse_hex;
+use abi::{ AbiError, Decoded, Function, ParamType, Value };
 use    bai::con::{ bytes_to_hex, hex_to_bytes };
 use kwl32::util::{       pad32l, pad32r       };
 use kwl32::util::{ xor32, and32, not32, or32  };
@@ -0,0 +1,96 @@
+/* ----------------------------------------------------------------------------
+   @title  : log - event logs, topics plus a data `View`
+   @author : Maka
+
+   @notice : log data is the same 32 byte word structure as calldata, minus
+             the selector, so it gets a `View` of its own. Topics are `Word`s,
+             the first is keccak of the event signature unless anonymous.
+// --------------------------------------------------------------------------*/
+
+use crate::prelude::*;
+
+use crate::{ View, Word, Calldata, WithSig };
+use crate::abi::{ self, AbiError, Decoded, Event, ParamType, Value };
+
+#[derive(Debug)]
+pub struct Log {
+  topics : Vec<Word>,
+  data   : View
+}
+
+impl Log {
+
+  pub fn new(topics : Vec<Word>, data : Calldata) -> Self {
+    Log { topics, data : View::new(data, WithSig::False) }
+  }
+  // topics and data as hex, prefixes optional
+  pub fn from_hex(topics : &[&str], data : &str) -> Self {
+    Self::new(
+      topics . iter() . map(|t| Word::from_hex(t)) . collect(),
+      Calldata::from_bytes(&hex_to_bytes(data.trim_start_matches("0x")))
+    )
+  }
+
+  pub fn topics(&self) -> &[Word] { &self.topics }
+
+  pub fn topic(&self, index : usize) -> Option<&Word> { self.topics.get(index) }
+
+  pub fn data(&self) -> &View { &self.data }
+
+  pub fn data_mut(&mut self) -> &mut View { &mut self.data }
+
+  // does this log belong to the event, by topic0 and topic count
+  pub fn matches(&self, event : &Event) -> bool {
+    let indexed = event.inputs . iter() . filter(|p| p.indexed) . count();
+    match event.anonymous {
+      true  => self.topics.len() == indexed,
+      false => self.topics.len() == indexed + 1
+            && self.topics[0].bytes() == event.topic0()
+    }
+  }
+
+  // decode indexed params from the topics and the rest from data
+  pub fn decode(&self, event : &Event) -> Result<Decoded, AbiError> {
+    if !self.matches(event) {
+      return Err(AbiError::Mismatch(format!("log is not {}", event.signature())))
+    }
+    let mut topics = self.topics[(!event.anonymous as usize)..] . iter();
+    let body = event.inputs . iter() . filter(|p| !p.indexed) . cloned() . collect::<Vec<_>>();
+    let mut body = abi::decode_params(&body, &self.data.data_bytes())? . into_iter();
+    let fields = event.inputs . iter() . map(|p| match p.indexed {
+      true  => {
+        let topic = topics . next() . map(|w| w.as_bytes32()) . unwrap_or([0;32]);
+        Ok((p.name.clone(), decode_topic(&p.kind, &topic)?))
+      },
+      false => body . next() . ok_or(AbiError::OutOfBounds(self.data.data_bytes().len()))
+    })
+    . collect::<Result<Vec<_>, AbiError>>()?;
+    Ok(Decoded { name : event.name.clone(), fields })
+  }
+  // as above, parsing the event signature first
+  pub fn decode_sig(&self, signature : &str) -> Result<Decoded, AbiError> {
+    self.decode(&Event::parse(signature)?)
+  }
+}
+
+// topic0 for an event signature, names and markers are fine
+pub fn topic0(signature : &str) -> Result<Word, AbiError> {
+  Event::parse(signature) . map(|e| Word::from_bytes(&e.topic0()))
+}
+
+// value types sit in the topic as is, anything else is hashed
+fn decode_topic(kind : &ParamType, topic : &[u8;32]) -> Result<Value, AbiError> {
+  match kind {
+    ParamType::Bytes | ParamType::String | ParamType::Array(_)
+    | ParamType::FixedArray(..) | ParamType::Tuple(_) => Ok(Value::Hashed(*topic)),
+    _ => abi::decode_value(kind, topic, 0)
+  }
+}
+
+use crate::bai::con::hex_to_bytes;
+
+/*
+   End of log.
+  //////////////////////////////////////////////////////////////////////// */
+/* --------------------------------------------------------------------------
+                                                      MIT License 2024 Maka  */
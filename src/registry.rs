@@ -0,0 +1,263 @@
+/* ----------------------------------------------------------------------------
+   @title  : registry - offline selector database
+   @author : Maka
+
+   @notice : maps 4 byte selectors to every text signature we know of,
+             collisions included. Ships with a bundled set of the usual
+             suspects, imports 4byte style dumps and abi json, and persists
+             as the same plain text it imports.
+   errors  : lines or entries that can't be read are skipped, imports return
+             how many signatures were actually taken on.
+// --------------------------------------------------------------------------*/
+
+use crate::prelude::*;
+
+use alloc::collections::BTreeMap;
+
+use crate::abi::{ Abi, canonical_signature };
+use crate::kec256::hash::selector;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Registry {
+  map : BTreeMap<[u8;4], Vec<String>>
+}
+
+#[allow(clippy::unused_unit)]
+impl Registry {
+
+  pub fn new() -> Self { Self::default() }
+
+  // registry preloaded with the bundled common signatures
+  pub fn bundled() -> Self {
+    let mut reg = Self::new();
+    BUNDLED . iter() . for_each(|sig| { reg.insert(sig); });
+    reg
+  }
+
+  // shared bundled registry, built once on first use
+  #[cfg(feature = "std")]
+  pub fn bundled_ref() -> &'static Registry {
+    static BUNDLE : std::sync::OnceLock<Registry> = std::sync::OnceLock::new();
+    BUNDLE . get_or_init(Registry::bundled)
+  }
+
+/* ----------------------------------------------------------------------------
+Registry cont..                  access
+-----------------------------------------------------------------------------*/
+
+  // all known signatures for a selector, empty if unknown
+  pub fn lookup(&self, sel : &[u8]) -> &[String] {
+    <[u8;4]>::try_from(sel) . ok()
+    . and_then(|key| self.map.get(&key))
+    . map(|sigs| sigs.as_slice())
+    . unwrap_or(&[])
+  }
+  // as above from a hex selector, prefix optional
+  pub fn lookup_hex(&self, hex : &str) -> &[String] {
+    self.lookup(&hex_to_bytes(hex.trim_start_matches("0x")))
+  }
+  // number of text signatures held, a collision counts each side
+  pub fn len(&self) -> usize {
+    self.map . values() . map(|sigs| sigs.len()) . sum()
+  }
+
+  pub fn is_empty(&self) -> bool { self.map.is_empty() }
+
+  // iterate selectors with their signatures, ordered by selector
+  pub fn iter(&self) -> impl Iterator<Item = (&[u8;4], &[String])> {
+    self.map . iter() . map(|(k, v)| (k, v.as_slice()))
+  }
+
+/* ----------------------------------------------------------------------------
+Registry cont..                  mutation
+-----------------------------------------------------------------------------*/
+
+  // add a text signature, returns its selector. One that parses is made
+  // canonical first, `transfer(address to, uint amount)` goes in as
+  // `transfer(address,uint256)`. Anything else just loses its whitespace
+  pub fn insert(&mut self, signature : &str) -> [u8;4] {
+    self._insert(signature) . 0
+  }
+  // fold another registry into this one
+  pub fn merge(&mut self, other : &Registry) -> () {
+    other.map . values() . flatten() . for_each(|sig| { self.insert(sig); })
+  }
+
+  // import a 4byte style text dump, one signature per line.
+  // accepts `sig`, `0xsel sig`, `sel,sig`, `id,sig,0xsel`.. any line with a
+  // selector is checked against the hash so a bad dump can't poison lookups
+  pub fn import_text(&mut self, dump : &str) -> usize {
+    dump . lines() . filter(|line| {
+      let line = line.trim();
+      if line.is_empty() || line.starts_with('#') { return false }
+      let (sig, rest) = match split_signature(line) { Some(x) => x, None => return false };
+      let sel = rest . split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+        . find(|f| is_selector_hex(f));
+      if let Some(sel) = sel {
+        if hex_to_bytes(sel.trim_start_matches("0x")) != selector(&normalize(sig)) { return false }
+      }
+      self.insert(sig); true
+    })
+    . count()
+  }
+
+  // import functions and errors from an abi json array, or an artifact
+  // object holding one under `abi`. Events carry topics, not selectors.
+  // An entry that can't be read is skipped, the rest still go in. Returns
+  // how many were new, ones already held aren't counted
+  pub fn import_abi_json(&mut self, text : &str) -> usize {
+    let abi = match Abi::from_json_lossy(text) { Ok((abi, _)) => abi, Err(_) => return 0 };
+    let sigs = abi.functions . iter() . map(|f| f.signature())
+      . chain(abi.errors . iter() . map(|e| e.signature()));
+    sigs . filter(|sig| self._insert(sig) . 1) . count()
+  }
+
+  // *private* insert, with whether the signature was new
+  fn _insert(&mut self, signature : &str) -> ([u8;4], bool) {
+    let text = normalize(signature); let sel = selector(&text);
+    let sigs = self.map . entry(sel) . or_default();
+    let new  = !sigs.contains(&text);
+    if new { sigs.push(text) }
+    (sel, new)
+  }
+
+/* ----------------------------------------------------------------------------
+Registry cont..                  persistence
+-----------------------------------------------------------------------------*/
+
+  // plain text form, `0xsel signature` per line, ordered by selector
+  pub fn to_text(&self) -> String {
+    let mut out = String::from(HEADER);
+    self.map . iter() . for_each(|(sel, sigs)| sigs . iter() . for_each(|sig| {
+      out += &format!("0x{} {}\n", bytes_to_hex(sel), sig)
+    }));
+    out
+  }
+
+  pub fn from_text(text : &str) -> Self {
+    let mut reg = Self::new(); reg.import_text(text); reg
+  }
+
+  #[cfg(feature = "std")]
+  pub fn save<P: AsRef<std::path::Path>>(&self, path : P) -> std::io::Result<()> {
+    std::fs::write(path, self.to_text())
+  }
+
+  #[cfg(feature = "std")]
+  pub fn load<P: AsRef<std::path::Path>>(path : P) -> std::io::Result<Self> {
+    std::fs::read_to_string(path) . map(|text| Self::from_text(&text))
+  }
+}
+
+/* ----------------------------------------------------------------------------
+ Helpers
+-----------------------------------------------------------------------------*/
+
+// canonical if it parses, otherwise as written without whitespace
+fn normalize(signature : &str) -> String {
+  canonical_signature(signature)
+    . unwrap_or_else(|_| signature . chars() . filter(|c| !c.is_whitespace()) . collect())
+}
+
+// pull the signature out of a dump line, its commas aren't separators.
+// returns it with the rest of the line, where a selector might live
+fn split_signature(line : &str) -> Option<(&str, String)> {
+  let open  = line.find('(')?;
+  let start = line[..open] . rfind(|c: char| c == ',' || c == ';' || c.is_whitespace())
+            . map(|i| i + 1) . unwrap_or(0);
+  let mut depth = 0; let mut end = None;
+  for (i, c) in line[open..].char_indices() {
+    match c {
+      '(' => depth += 1,
+      ')' => { depth -= 1; if depth == 0 { end = Some(open + i + 1); break } },
+      _   => ()
+    }
+  }
+  let end = end?;
+  match start < open {
+    true  => Some((&line[start..end], line[..start].to_owned() + " " + &line[end..])),
+    false => None
+  }
+}
+
+fn is_selector_hex(text : &str) -> bool {
+  let hex = text.trim_start_matches("0x");
+  hex.len() == 8 && hex . chars() . all(|c| c.is_ascii_hexdigit())
+}
+
+const HEADER : &str = "# kawala registry - 0xselector signature\n";
+
+// common signatures, selectors are derived on load
+const BUNDLED : &[&str] = &[
+  /* erc20 */
+  "transfer(address,uint256)", "transferFrom(address,address,uint256)",
+  "approve(address,uint256)", "balanceOf(address)", "allowance(address,address)",
+  "totalSupply()", "decimals()", "name()", "symbol()",
+  "increaseAllowance(address,uint256)", "decreaseAllowance(address,uint256)",
+  /* weth */
+  "deposit()", "withdraw(uint256)",
+  /* permit, eip2612 / dai / permit2 */
+  "permit(address,address,uint256,uint256,uint8,bytes32,bytes32)",
+  "permit(address,address,uint256,uint256,bool,uint8,bytes32,bytes32)",
+  "permit(address,((address,uint160,uint48,uint48),address,uint256),bytes)",
+  "permitTransferFrom(((address,uint256),uint256,uint256),(address,uint256),address,bytes)",
+  "nonces(address)", "DOMAIN_SEPARATOR()",
+  /* erc721 */
+  "ownerOf(uint256)", "safeTransferFrom(address,address,uint256)",
+  "safeTransferFrom(address,address,uint256,bytes)", "setApprovalForAll(address,bool)",
+  "getApproved(uint256)", "isApprovedForAll(address,address)", "tokenURI(uint256)",
+  "supportsInterface(bytes4)", "burn(uint256)", "mint(address,uint256)",
+  /* erc1155 */
+  "safeTransferFrom(address,address,uint256,uint256,bytes)",
+  "safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)",
+  "balanceOf(address,uint256)", "balanceOfBatch(address[],uint256[])", "uri(uint256)",
+  /* uniswap v2 router */
+  "swapExactTokensForTokens(uint256,uint256,address[],address,uint256)",
+  "swapTokensForExactTokens(uint256,uint256,address[],address,uint256)",
+  "swapExactETHForTokens(uint256,address[],address,uint256)",
+  "swapTokensForExactETH(uint256,uint256,address[],address,uint256)",
+  "swapExactTokensForETH(uint256,uint256,address[],address,uint256)",
+  "swapETHForExactTokens(uint256,address[],address,uint256)",
+  "swapExactTokensForTokensSupportingFeeOnTransferTokens(uint256,uint256,address[],address,uint256)",
+  "swapExactETHForTokensSupportingFeeOnTransferTokens(uint256,address[],address,uint256)",
+  "swapExactTokensForETHSupportingFeeOnTransferTokens(uint256,uint256,address[],address,uint256)",
+  "addLiquidity(address,address,uint256,uint256,uint256,uint256,address,uint256)",
+  "addLiquidityETH(address,uint256,uint256,uint256,address,uint256)",
+  "removeLiquidity(address,address,uint256,uint256,uint256,address,uint256)",
+  "removeLiquidityETH(address,uint256,uint256,uint256,address,uint256)",
+  "getAmountsOut(uint256,address[])", "getAmountsIn(uint256,address[])",
+  /* uniswap v2 pair */
+  "swap(uint256,uint256,address,bytes)", "getReserves()", "sync()", "skim(address)",
+  /* uniswap v3 routers */
+  "exactInputSingle((address,address,uint24,address,uint256,uint256,uint256,uint160))",
+  "exactInputSingle((address,address,uint24,address,uint256,uint256,uint160))",
+  "exactInput((bytes,address,uint256,uint256,uint256))",
+  "exactInput((bytes,address,uint256,uint256))",
+  "exactOutputSingle((address,address,uint24,address,uint256,uint256,uint256,uint160))",
+  "exactOutput((bytes,address,uint256,uint256,uint256))",
+  /* universal router */
+  "execute(bytes,bytes[],uint256)", "execute(bytes,bytes[])",
+  /* multicall */
+  "multicall(bytes[])", "multicall(uint256,bytes[])", "multicall(bytes32,bytes[])",
+  "aggregate((address,bytes)[])", "tryAggregate(bool,(address,bytes)[])",
+  "blockAndAggregate((address,bytes)[])", "aggregate3((address,bool,bytes)[])",
+  "aggregate3Value((address,bool,uint256,bytes)[])",
+  /* safe */
+  "execTransaction(address,uint256,bytes,uint8,uint256,uint256,uint256,address,address,bytes)",
+  "execTransactionFromModule(address,uint256,bytes,uint8)",
+  "getTransactionHash(address,uint256,bytes,uint8,uint256,uint256,uint256,address,address,uint256)",
+  "approveHash(bytes32)", "addOwnerWithThreshold(address,uint256)",
+  "removeOwner(address,address,uint256)", "swapOwner(address,address,address)",
+  "changeThreshold(uint256)", "enableModule(address)", "disableModule(address,address)",
+  "setup(address[],uint256,address,bytes,address,address,uint256,address)", "multiSend(bytes)",
+  /* revert payloads */
+  "Error(string)", "Panic(uint256)"
+];
+
+use crate::bai::con::{ bytes_to_hex, hex_to_bytes };
+
+/*
+   End of registry.
+  //////////////////////////////////////////////////////////////////////// */
+/* --------------------------------------------------------------------------
+                                                      MIT License 2024 Maka  */
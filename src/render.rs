@@ -0,0 +1,247 @@
+/* ----------------------------------------------------------------------------
+   @title  : render - column aware printing of a `View`
+   @author : Maka
+
+   @notice : the cleaner default look for large pages. One word per line with
+             its index and byte offset, runs of zero words folded, slot names
+             or notes alongside. Written to any `fmt::Write` or `io::Write`,
+             and the same view always renders the same text.
+             For packed streams and embedded strings there is `Hexdump`, the
+             classic offset, bytes and ascii gutter, which reads back in.
+// --------------------------------------------------------------------------*/
+
+use crate::prelude::*;
+
+use alloc::collections::BTreeMap;
+use core::fmt;
+use core::ops::Range;
+
+use crate::View;
+use crate::error::KawalaError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Render {
+  index   : bool,
+  offset  : bool,
+  prefix  : bool,
+  // shortest run of zero words to fold into one line, 0 for never
+  zeros   : usize,
+  slots   : bool,
+  notes   : BTreeMap<usize, String>,
+  widths  : (usize, usize)
+}
+
+impl Render {
+
+  // index and offset columns, zero runs of 2+ folded, slot names when bound
+  pub fn new() -> Self {
+    Render {
+      index : true, offset : true, prefix : false, zeros : 2, slots : true,
+      notes : BTreeMap::new(), widths : (0, 0)
+    }
+  }
+
+  pub fn index(mut self, on : bool) -> Self { self.index = on; self }
+
+  pub fn offset(mut self, on : bool) -> Self { self.offset = on; self }
+  // `0x` in front of the sig and words
+  pub fn prefix(mut self, on : bool) -> Self { self.prefix = on; self }
+  // fold runs of at least `min` zero words, 0 to show every one
+  pub fn zero_runs(mut self, min : usize) -> Self { self.zeros = min; self }
+  // note the parameter each head word starts, from the bound abi
+  pub fn slot_names(mut self, on : bool) -> Self { self.slots = on; self }
+  // a note for a word, replaces the view's note and its slot name. Noted
+  // words aren't folded
+  pub fn annotate(mut self, index : usize, note : &str) -> Self {
+    self.notes.insert(index, note.to_owned()); self
+  }
+  // minimum widths of the index and offset columns, 0 to fit the page
+  pub fn widths(mut self, index : usize, offset : usize) -> Self {
+    self.widths = (index, offset); self
+  }
+
+  // render to a string
+  pub fn render(&self, view : &View) -> String {
+    let mut out = String::new();
+    let _ = self.render_to(view, &mut out);
+    out
+  }
+  // render to any `fmt::Write`
+  pub fn render_to<W: fmt::Write>(&self, view : &View, out : &mut W) -> fmt::Result {
+    let pre  = if self.prefix { "0x" } else { "" };
+    let rows = self._rows(view);
+    // columns fit the widest entry, or the caller's width if wider
+    let iw = rows . iter() . map(|r| r.0.len()) . max() . unwrap_or(1) . max(self.widths.0);
+    let ow = rows . iter() . map(|r| format!("{:x}", r.1).len()) . max() . unwrap_or(2) . max(2) . max(self.widths.1);
+    if !view.sig().is_empty() { writeln!(out, "sig {}{}", pre, view.sig())? }
+    for (index, offset, body, note) in rows {
+      if self.index  { write!(out, "{:>w$}  ", index, w = iw)? }
+      if self.offset { write!(out, "0x{:0w$x}  ", offset, w = ow)? }
+      match body {
+        Ok(hex)  => write!(out, "{}{}", pre, hex)?,
+        Err(run) => write!(out, "{} zero words", run)?
+      }
+      match note {
+        Some(note) => writeln!(out, "  // {}", note)?,
+        None       => writeln!(out)?
+      }
+    }
+    Ok(())
+  }
+  // render to any `io::Write`
+  #[cfg(feature = "std")]
+  pub fn render_io<W: std::io::Write>(&self, view : &View, out : &mut W) -> std::io::Result<()> {
+    out.write_all(self.render(view).as_bytes())
+  }
+
+  // index label, byte offset, word hex and note per line. A folded run of
+  // zero words reads `first..last` and has its length for a body
+  fn _rows(&self, view : &View) -> Vec<Row> {
+    let words = view.iter() . collect::<Vec<_>>();
+    let mut rows = Vec::new(); let mut i = 0;
+    while i < words.len() {
+      let (_, offset, word) = words[i];
+      let run = words[i..] . iter()
+        . take_while(|(j, _, w)| w.is_zero() && self._note(view, *j).is_none()) . count();
+      match self.zeros > 0 && run >= self.zeros && run > 1 {
+        true  => {
+          rows.push((format!("{}..{}", i, i + run - 1), offset, Err(run), None));
+          i += run
+        },
+        false => {
+          rows.push((i.to_string(), offset, Ok(word.hex()), self._note(view, i)));
+          i += 1
+        }
+      }
+    }
+    rows
+  }
+
+  fn _note(&self, view : &View, index : usize) -> Option<String> {
+    self.notes . get(&index) . cloned()
+    . or_else(|| view.note(index) . map(|x| x.to_owned()))
+    . or_else(|| if self.slots { view.slot_name(index) } else { None })
+  }
+}
+
+impl Default for Render {
+  fn default() -> Self { Self::new() }
+}
+
+/* ----------------------------------------------------------------------------
+ Hexdump
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* rows of `width` bytes under an 8 digit hex offset, printable ascii in the
+     gutter and `.` for the rest. A word marker is a `:` before the first byte
+     of a word, a highlight is a `^^` rule under the row, or inverse video with
+     colour on. A last row holds only the total length, as `hexdump -C` does */
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hexdump {
+  width  : usize,
+  marks  : bool,
+  lights : Vec<Range<usize>>,
+  colour : bool
+}
+
+impl Hexdump {
+
+  // 16 bytes a row, no markers, no highlights
+  pub fn new() -> Self {
+    Hexdump { width : 16, marks : false, lights : Vec::new(), colour : false }
+  }
+  // bytes per row, at least one
+  pub fn width(mut self, width : usize) -> Self { self.width = width.max(1); self }
+  // mark where each word starts
+  pub fn word_marks(mut self, on : bool) -> Self { self.marks = on; self }
+  // highlight a byte range, offsets as printed. Can be called again for more
+  pub fn highlight(mut self, range : Range<usize>) -> Self { self.lights.push(range); self }
+  // ansi inverse video for highlights, in place of the rule
+  pub fn colour(mut self, on : bool) -> Self { self.colour = on; self }
+
+  // dump raw bytes, words start every 32 bytes from the first
+  pub fn dump(&self, bytes : &[u8]) -> String {
+    let starts = (WORD..bytes.len()) . step_by(WORD) . collect::<Vec<_>>();
+    self._dump(bytes, &starts)
+  }
+  // dump a view, sig included. Words start where the view chunked them
+  pub fn dump_view(&self, view : &View) -> String {
+    let sig = view.sig() . len() / 2;
+    let starts = view.iter() . map(|(_, o, _)| sig + o) . filter(|o| *o > 0) . collect::<Vec<_>>();
+    self._dump(&view.bytes(), &starts)
+  }
+
+  // *private*
+  fn _dump(&self, bytes : &[u8], starts : &[usize]) -> String {
+    let mut out = String::new();
+    for (row, chunk) in bytes . chunks(self.width) . enumerate() {
+      let at = row * self.width;
+      let lit = |i : usize| self.lights . iter() . any(|r| r.contains(&i));
+      let mut hex = String::new(); let mut gutter = String::new(); let mut rule = String::new();
+      for (i, b) in chunk . iter() . enumerate() {
+        let (on, off) = if self.colour && lit(at + i) { ("\x1b[7m", "\x1b[0m") } else { ("", "") };
+        let mark = if self.marks && starts.contains(&(at + i)) { ':' } else { ' ' };
+        let c = if (0x20..0x7f).contains(b) { *b as char } else { '.' };
+        hex    += &format!("{}{}{:02x}{}", mark, on, b, off);
+        gutter += &format!("{}{}{}", on, c, off);
+        rule   += if !self.colour && lit(at + i) { " ^^" } else { "   " };
+      }
+      let pad = "   ".repeat(self.width - chunk.len());
+      out += &format!("{:08x} {}{}  |{}|\n", at, hex, pad, gutter);
+      if !rule.trim().is_empty() { out += &format!("{:8} {}\n", "", rule.trim_end()) }
+    }
+    out + &format!("{:08x}\n", bytes.len())
+  }
+}
+
+impl Default for Hexdump {
+  fn default() -> Self { Self::new() }
+}
+
+// reads a dump back to bytes, edits and all. The offset column and gutter
+// are ignored, so rows can grow or shrink in an editor. Rules, colour and
+// blank lines are skipped, a byte that isn't two hex digits is an error
+pub fn parse_hexdump(text : &str) -> Result<Vec<u8>, KawalaError> {
+  let mut bytes = Vec::new();
+  for line in text . lines() . map(_strip_ansi) {
+    if line.trim().is_empty() || line.starts_with(char::is_whitespace) { continue }
+    let body = line . split('|') . next() . unwrap_or("");
+    let mut tokens = body . split_whitespace();
+    let offset = tokens . next() . unwrap_or("");
+    if !offset . chars() . all(|c| c.is_ascii_hexdigit()) { return Err(KawalaError::Hex(offset.to_owned())) }
+    for token in tokens . flat_map(|t| t.split(':')) . filter(|t| !t.is_empty()) {
+      match token.len() == 2 && token . chars() . all(|c| c.is_ascii_hexdigit()) {
+        true  => bytes.push(hex_to_bytes(token)[0]),
+        false => return Err(KawalaError::Hex(token.to_owned()))
+      }
+    }
+  }
+  Ok(bytes)
+}
+
+// *private* drops `esc[..m` sequences
+fn _strip_ansi(line : &str) -> String {
+  let mut out = String::new(); let mut chars = line.chars();
+  while let Some(c) = chars.next() {
+    match c == '\x1b' {
+      true  => { chars . by_ref() . find(|c| *c == 'm'); },
+      false => out.push(c)
+    }
+  }
+  out
+}
+
+// *private* index label, offset, word hex or folded run length, note
+type Row = (String, usize, Result<String, usize>, Option<String>);
+
+const WORD : usize = 32;
+
+use crate::bai::con::hex_to_bytes;
+
+/*
+   End of render.
+  //////////////////////////////////////////////////////////////////////// */
+/* --------------------------------------------------------------------------
+                                                      MIT License 2024 Maka  */
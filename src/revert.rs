@@ -0,0 +1,177 @@
+/* ----------------------------------------------------------------------------
+   @title  : revert - revert and return data, explained
+   @author : Maka
+
+   @notice : what a failing sim hands back. `Error(string)` and
+             `Panic(uint256)` are built in, custom errors resolve through a
+             registry or a supplied signature, and plain return data decodes
+             against the output types we expected to get.
+// --------------------------------------------------------------------------*/
+
+use crate::prelude::*;
+
+use core::fmt;
+
+use crate::Registry;
+#[cfg(feature = "std")]
+use crate::View;
+use crate::abi::{ self, AbiError, CustomError, Decoded, ParamType };
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Revert {
+  // no data at all, a bare `revert()`, a failed `require` without a message,
+  // or the call ran out of gas
+  Empty,
+  // `Error(string)`, a `require` or `revert` with a message
+  Error (String),
+  // `Panic(uint256)`, the compiler's own checks
+  Panic ([u8;32]),
+  // a custom error we could resolve
+  Custom(Decoded),
+  // a selector we couldn't resolve, with whatever the registry guessed
+  Unknown { selector : [u8;4], data : Vec<u8>, candidates : Vec<String> }
+}
+
+impl Revert {
+
+  // decode, resolving custom errors through the bundled registry
+  #[cfg(feature = "std")]
+  pub fn decode(data : &[u8]) -> Revert {
+    Self::decode_in(data, Registry::bundled_ref())
+  }
+  // as above, through a supplied registry. First candidate to decode wins
+  pub fn decode_in(data : &[u8], registry : &Registry) -> Revert {
+    if let Some(builtin) = builtin(data) { return builtin }
+    let sel = selector_of(data);
+    let candidates = registry.lookup(&sel).to_vec();
+    candidates . iter()
+    . filter_map(|sig| CustomError::parse(sig) . ok())
+    . find_map(|err| err.decode(data) . ok())
+    . map(Revert::Custom)
+    . unwrap_or(Revert::Unknown { selector : sel, data : data.get(4..) . unwrap_or(&[]) . to_vec(), candidates })
+  }
+  // decode against a known custom error signature, names welcome
+  pub fn decode_with(data : &[u8], signature : &str) -> Result<Revert, AbiError> {
+    if let Some(builtin) = builtin(data) { return Ok(builtin) }
+    CustomError::parse(signature)? . decode(data) . map(Revert::Custom)
+  }
+  // decode against a set of known custom errors, an abi's for instance
+  pub fn decode_against(data : &[u8], errors : &[CustomError]) -> Revert {
+    if let Some(builtin) = builtin(data) { return builtin }
+    let sel = selector_of(data);
+    errors . iter() . filter(|err| err.selector() == sel)
+    . find_map(|err| err.decode(data) . ok())
+    . map(Revert::Custom)
+    . unwrap_or(Revert::Unknown { selector : sel, data : data.get(4..) . unwrap_or(&[]) . to_vec(), candidates : Vec::new() })
+  }
+  // decode the complete calldata of a view, sig as the error selector
+  #[cfg(feature = "std")]
+  pub fn from_view(view : &View) -> Revert {
+    Self::decode(&view.bytes())
+  }
+
+  // panic code as a number, if this is a panic that fits one
+  pub fn panic_code(&self) -> Option<u64> {
+    match self {
+      Revert::Panic(w) if w[..24] . iter() . all(|b| *b == 0) =>
+        Some(u64::from_be_bytes(w[24..] . try_into() . unwrap_or([0;8]))),
+      _ => None
+    }
+  }
+}
+
+impl fmt::Display for Revert {
+  fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Revert::Empty        => write!(f, "reverted without data (bare revert, require without message, or out of gas)"),
+      Revert::Error(msg)   => write!(f, "reverted: {}", msg),
+      Revert::Panic(w)     => match self.panic_code() {
+        Some(code) => write!(f, "panic 0x{:02x}: {}", code, panic_reason(code)),
+        None       => write!(f, "panic 0x{}: unknown code", bytes_to_hex(w))
+      },
+      Revert::Custom(d)    => write!(f, "custom error {}", d),
+      Revert::Unknown { selector, data, candidates } => {
+        write!(f, "unknown error 0x{} with {} bytes of data", bytes_to_hex(selector), data.len())?;
+        match candidates.is_empty() {
+          true  => Ok(()),
+          false => write!(f, ", didn't decode as {}", candidates.join(" or "))
+        }
+      }
+    }
+  }
+}
+
+// one call from raw bytes to a readable reason
+#[cfg(feature = "std")]
+pub fn explain(data : &[u8]) -> String {
+  Revert::decode(data).to_string()
+}
+
+// the meaning of a solidity panic code
+pub fn panic_reason(code : u64) -> &'static str {
+  match code {
+    0x00 => "generic compiler panic",
+    0x01 => "assertion failed",
+    0x11 => "arithmetic overflow or underflow",
+    0x12 => "division or modulo by zero",
+    0x21 => "conversion to an invalid enum value",
+    0x22 => "incorrectly encoded storage byte array",
+    0x31 => "pop on an empty array",
+    0x32 => "array index out of bounds",
+    0x41 => "out of memory, too much allocated",
+    0x51 => "call to a zero initialized internal function",
+    _    => "unknown code"
+  }
+}
+
+// decode plain return data against the output types, `uint256[] amounts`
+pub fn decode_return(data : &[u8], outputs : &str) -> Result<Decoded, AbiError> {
+  let outputs = outputs.trim();
+  // take the list with or without its parens, `(uint256,address)` is the list
+  let list = match wrapped(outputs) { true => &outputs[1..outputs.len() - 1], false => outputs };
+  let params = abi::parse_params(list)?;
+  Ok(Decoded { name : String::new(), fields : abi::decode_params(&params, data)? })
+}
+
+/* ----------------------------------------------------------------------------
+ Helpers
+-----------------------------------------------------------------------------*/
+
+fn selector_of(data : &[u8]) -> [u8;4] {
+  data . get(..4) . and_then(|s| s . try_into() . ok()) . unwrap_or([0;4])
+}
+
+// is the whole text inside one pair of parens
+fn wrapped(text : &str) -> bool {
+  let mut depth = 0;
+  text.starts_with('(') && text . char_indices() . all(|(i, c)| {
+    match c { '(' => depth += 1, ')' => depth -= 1, _ => () }
+    depth > 0 || i == text.len() - 1
+  })
+}
+
+// Empty, Error(string) and Panic(uint256) need no registry
+fn builtin(data : &[u8]) -> Option<Revert> {
+  if data.is_empty() { return Some(Revert::Empty) }
+  let body = data . get(4..) . unwrap_or(&[]);
+  match selector_of(data) {
+    ERROR_SEL => match abi::decode(&[ParamType::String], body) {
+      Ok(mut v) => match v.pop() { Some(abi::Value::String(s)) => Some(Revert::Error(s)), _ => None },
+      Err(_)    => None
+    },
+    PANIC_SEL => body . get(..32) . map(|w| Revert::Panic(w . try_into() . unwrap_or([0;32]))),
+    _         => None
+  }
+}
+
+// Error(string) and Panic(uint256)
+const ERROR_SEL : [u8;4] = [0x08, 0xc3, 0x79, 0xa0];
+const PANIC_SEL : [u8;4] = [0x4e, 0x48, 0x7b, 0x71];
+
+use crate::bai::con::bytes_to_hex;
+
+/*
+   End of revert.
+  //////////////////////////////////////////////////////////////////////// */
+/* --------------------------------------------------------------------------
+                                                      MIT License 2024 Maka  */
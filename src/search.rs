@@ -0,0 +1,127 @@
+/* ----------------------------------------------------------------------------
+   @title  : search - where a value turns up in a call
+   @author : Maka
+
+   @notice : an address or an amount can sit in a call three ways. As a whole
+             abi word, padded left like a number or right like fixed bytes.
+             As its own bytes somewhere, packed or off a word boundary. Or
+             inside a `bytes` value, a multicall's inner calls, where it can
+             be either of those again relative to the payload. A payload 4
+             bytes over a word multiple is taken as a call, its words start
+             after the selector. Replacing writes each match back the way it
+             was found, so nothing moves and no offset needs fixing.
+   errors  : a replacement too long for the word or packed bytes it goes in.
+// --------------------------------------------------------------------------*/
+
+use crate::prelude::*;
+
+use core::ops::Range;
+
+use crate::abi::{ self, Link };
+use crate::error::KawalaError;
+
+// the side of a word the padding went, numbers and addresses left, fixed bytes right
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pad { Left, Right }
+
+// one place a value turned up. Offsets count from the first byte after the
+// sig, inside a payload from the payload's first byte
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Found {
+  // the `index`th abi word, starting at `offset`, the value padded into it
+  Word   { index : usize, offset : usize, pad : Pad },
+  // the value's own bytes from `offset`, not a word of their own
+  Bytes  { offset : usize },
+  // inside a `bytes` value whose payload starts at `payload`, found there as `inner`
+  Nested { payload : usize, inner : Box<Found> }
+}
+
+impl Found {
+
+  // where the match starts in the outermost data, a word match at its word
+  pub fn offset(&self) -> usize {
+    match self {
+      Found::Word { offset, .. } | Found::Bytes { offset } => *offset,
+      Found::Nested { payload, inner }                     => payload + inner.offset()
+    }
+  }
+  // how many payloads down
+  pub fn depth(&self) -> usize {
+    match self { Found::Nested { inner, .. } => ONE + inner.depth(), _ => ZERO }
+  }
+  // the innermost match, a `Word` or `Bytes`
+  pub fn leaf(&self) -> &Found {
+    match self { Found::Nested { inner, .. } => inner.leaf(), _ => self }
+  }
+  // bytes to write at `offset()` to put `new` where `old` was found. A word
+  // takes `new` padded the same side, packed bytes keep their width with a
+  // shorter `new` left padded like a number
+  pub fn patch(&self, old : &[u8], new : &[u8]) -> Result<Vec<u8>, KawalaError> {
+    match self {
+      Found::Word { .. } if new.len() > WORD => Err(KawalaError::TooLong { max : WORD, found : new.len() }),
+      Found::Word { pad : Pad::Left, .. }    => Ok(pad32l(new).to_vec()),
+      Found::Word { pad : Pad::Right, .. }   => Ok(pad32r(new).to_vec()),
+      Found::Bytes { .. } if new.len() > old.len() => Err(KawalaError::TooLong { max : old.len(), found : new.len() }),
+      Found::Bytes { .. }                    => Ok([vec![0; old.len() - new.len()], new.to_vec()].concat()),
+      Found::Nested { inner, .. }            => inner.patch(old, new)
+    }
+  }
+}
+
+// every place `value` turns up in abi encoded `data`, in order. `links` are
+// the data's offset and length words, `View::links` or `abi::infer_links`.
+// Payloads come first, then whole words, then the bytes anywhere else
+pub fn find_value(data : &[u8], links : &[Link], value : &[u8]) -> Vec<Found> {
+  _search(data, ZERO, links, value)
+}
+
+// *private* as above, words start `skip` bytes in and `links` count from there
+fn _search(data : &[u8], skip : usize, links : &[Link], value : &[u8]) -> Vec<Found> {
+  let mut out = Vec::new(); let mut claimed : Vec<Range<usize>> = Vec::new();
+  if value.is_empty() { return out }
+  let clear = |claimed : &[Range<usize>], span : &Range<usize>| !claimed . iter() . any(|c| c.start < span.end && span.start < c.end);
+  // payloads, searched as data of their own
+  for link in links {
+    let (at, count) = match *link { Link::Length { at, count, unit : ONE } => (skip + at, count), _ => continue };
+    let span = at + WORD..at + WORD + count;
+    if span.end > data.len() || !clear(&claimed, &span) { continue }
+    let payload = &data[span.clone()];
+    let inner   = match count % WORD { SIG => SIG, _ => ZERO };
+    let found   = _search(payload, inner, &abi::infer_links(&payload[inner..]), value);
+    out . extend(found . into_iter() . map(|f| Found::Nested { payload : span.start, inner : Box::new(f) }));
+    claimed.push(span);
+  }
+  // whole words, padded either side
+  if value.len() <= WORD {
+    let (left, right) = (pad32l(value), pad32r(value));
+    for (index, word) in data[core::cmp::min(skip, data.len())..] . chunks_exact(WORD) . enumerate() {
+      let offset = skip + index * WORD; let span = offset..offset + WORD;
+      let pad = match word { w if *w == left => Pad::Left, w if *w == right => Pad::Right, _ => continue };
+      if clear(&claimed, &span) { out.push(Found::Word { index, offset, pad }); claimed.push(span) }
+    }
+  }
+  // anything left, non overlapping from the front
+  let mut pos = ZERO;
+  while pos + value.len() <= data.len() {
+    let span = pos..pos + value.len();
+    match data[span.clone()] == *value && clear(&claimed, &span) {
+      true  => { out.push(Found::Bytes { offset : pos }); pos = span.end },
+      false => pos += ONE
+    }
+  }
+  out . sort_by_key(|f| f.offset());
+  out
+}
+
+const ZERO : usize = 0;
+const ONE  : usize = 1;
+const SIG  : usize = 4;
+const WORD : usize = 32;
+
+use crate::kwl32::util::{ pad32l, pad32r };
+
+/*
+   End of search.
+  //////////////////////////////////////////////////////////////////////// */
+/* --------------------------------------------------------------------------
+                                                      MIT License 2024 Maka  */
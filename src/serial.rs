@@ -0,0 +1,253 @@
+/* ----------------------------------------------------------------------------
+   @title  : serial - serde for the core types, behind the `serde` feature
+   @author : Maka
+
+   @notice : calldata in json fixtures, configs and api bodies. Human readable
+             formats get `0x` hex strings, binary formats get the raw bytes.
+             A `View` travels as `{ sig, data }`, `sig` null for a view
+             without one, so any header comes back as it went. Its chunking,
+             abi and edit mode stay behind. Decoded values serialize
+             as a tree, numbers as decimal strings so nothing over 64 bits is
+             lost. They don't deserialize, there is no type to read them by,
+             `Value::parse` against a `ParamType` does that.
+// --------------------------------------------------------------------------*/
+
+use crate::prelude::*;
+
+use core::fmt;
+
+use serde::de::{ self, Deserializer, MapAccess, SeqAccess, Visitor };
+use serde::ser::{ SerializeMap, SerializeSeq, SerializeStruct, Serializer };
+use serde::{ Deserialize, Serialize };
+
+use crate::{ Bytes, Calldata, Signature, View, ViewConfig, Word };
+use crate::abi::{ Decoded, Value };
+use crate::error::parse_hex;
+
+/* ----------------------------------------------------------------------------
+ Bytes types
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+impl Serialize for Bytes {
+  fn serialize<S: Serializer>(&self, s : S) -> Result<S::Ok, S::Error> { _hex_or_bytes(self.bytes(), s) }
+}
+
+impl Serialize for Word {
+  fn serialize<S: Serializer>(&self, s : S) -> Result<S::Ok, S::Error> { _hex_or_bytes(self.bytes(), s) }
+}
+
+impl Serialize for Signature {
+  fn serialize<S: Serializer>(&self, s : S) -> Result<S::Ok, S::Error> { _hex_or_bytes(self.bytes(), s) }
+}
+
+impl Serialize for Calldata {
+  fn serialize<S: Serializer>(&self, s : S) -> Result<S::Ok, S::Error> { _hex_or_bytes(self.bytes(), s) }
+}
+
+// `{ sig, data }`, the sig as long as the header and null without one
+impl Serialize for View {
+  fn serialize<S: Serializer>(&self, s : S) -> Result<S::Ok, S::Error> {
+    let mut out = s.serialize_struct("View", 2)?;
+    out.serialize_field("sig", &self.sig . as_ref() . map(|_| Raw(self.sig_bytes())))?;
+    out.serialize_field("data", &Raw(&self.data_bytes()))?;
+    out.end()
+  }
+}
+
+// a 4 or 32 byte run keeps its fixed variant
+impl<'de> Deserialize<'de> for Bytes {
+  fn deserialize<D: Deserializer<'de>>(d : D) -> Result<Self, D::Error> {
+    let bytes = _read(d)?;
+    Ok(match bytes.len() {
+      4  => Bytes::Bytes4 (bytes . try_into() . unwrap_or_default()),
+      32 => Bytes::Bytes32(bytes . try_into() . unwrap_or([0;32])),
+      _  => Bytes::Array(bytes)
+    })
+  }
+}
+
+// at most a word, anything longer is an error rather than truncated
+impl<'de> Deserialize<'de> for Word {
+  fn deserialize<D: Deserializer<'de>>(d : D) -> Result<Self, D::Error> {
+    Word::try_from_bytes(&_read(d)?) . map_err(de::Error::custom)
+  }
+}
+
+// exactly 4 bytes
+impl<'de> Deserialize<'de> for Signature {
+  fn deserialize<D: Deserializer<'de>>(d : D) -> Result<Self, D::Error> {
+    Signature::try_from_bytes(&_read(d)?) . map_err(de::Error::custom)
+  }
+}
+
+impl<'de> Deserialize<'de> for Calldata {
+  fn deserialize<D: Deserializer<'de>>(d : D) -> Result<Self, D::Error> {
+    _read(d) . map(Calldata::from)
+  }
+}
+
+// a header as long as the sig, none for a null one, over 32 byte words
+impl<'de> Deserialize<'de> for View {
+  fn deserialize<D: Deserializer<'de>>(d : D) -> Result<Self, D::Error> {
+    d.deserialize_struct("View", VIEW_FIELDS, ViewParts)
+  }
+}
+
+/* ----------------------------------------------------------------------------
+ Abi values
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* bools as bools, strings as strings, lists and tuples as sequences.
+     Numbers are their decimal `Display`, addresses and bytes go the way of
+     the bytes types above, a hashed value is `keccak(0x..)` as displayed */
+
+impl Serialize for Value {
+  fn serialize<S: Serializer>(&self, s : S) -> Result<S::Ok, S::Error> {
+    match self {
+      Value::Bool(b)        => s.serialize_bool(*b),
+      Value::String(t)      => s.serialize_str(t),
+      Value::Address(a)     => _hex_or_bytes(a, s),
+      Value::FixedBytes(b)  |
+      Value::Bytes(b)       => _hex_or_bytes(b, s),
+      Value::Uint(w)        |
+      Value::Int(w)         => match s.is_human_readable() {
+        true  => s.collect_str(self),
+        false => s.serialize_bytes(w)
+      },
+      Value::Hashed(_)      => s.collect_str(self),
+      Value::Array(vs)      |
+      Value::FixedArray(vs) |
+      Value::Tuple(vs)      => {
+        let mut seq = s.serialize_seq(Some(vs.len()))?;
+        vs . iter() . try_for_each(|v| seq.serialize_element(v))?;
+        seq.end()
+      }
+    }
+  }
+}
+
+// `{ name, fields : { name : value } }`, an unnamed field keyed by position
+impl Serialize for Decoded {
+  fn serialize<S: Serializer>(&self, s : S) -> Result<S::Ok, S::Error> {
+    let mut out = s.serialize_struct("Decoded", 2)?;
+    out.serialize_field("name", &self.name)?;
+    out.serialize_field("fields", &Fields(&self.fields))?;
+    out.end()
+  }
+}
+
+// *private*
+struct Fields<'a>(&'a [(String, Value)]);
+
+impl Serialize for Fields<'_> {
+  fn serialize<S: Serializer>(&self, s : S) -> Result<S::Ok, S::Error> {
+    let mut map = s.serialize_map(Some(self.0.len()))?;
+    for (i, (name, value)) in self.0 . iter() . enumerate() {
+      match name.is_empty() {
+        true  => map.serialize_entry(&i.to_string(), value)?,
+        false => map.serialize_entry(name, value)?
+      }
+    }
+    map.end()
+  }
+}
+
+// *private* bytes as a field, both ways
+struct Raw<'a>(&'a [u8]);
+struct RawOwned(Vec<u8>);
+
+impl Serialize for Raw<'_> {
+  fn serialize<S: Serializer>(&self, s : S) -> Result<S::Ok, S::Error> { _hex_or_bytes(self.0, s) }
+}
+
+impl<'de> Deserialize<'de> for RawOwned {
+  fn deserialize<D: Deserializer<'de>>(d : D) -> Result<Self, D::Error> { _read(d) . map(RawOwned) }
+}
+
+// *private* a view from its fields, as a map or in order
+struct ViewParts;
+
+impl<'de> Visitor<'de> for ViewParts {
+  type Value = View;
+
+  fn expecting(&self, f : &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "a view, its sig and data")
+  }
+
+  fn visit_seq<A: SeqAccess<'de>>(self, mut seq : A) -> Result<View, A::Error> {
+    let sig  = seq.next_element::<Option<RawOwned>>()? . ok_or_else(|| de::Error::invalid_length(0, &self))?;
+    let data = seq.next_element::<RawOwned>()? . ok_or_else(|| de::Error::invalid_length(1, &self))?;
+    Ok(_view(sig, data))
+  }
+
+  fn visit_map<A: MapAccess<'de>>(self, mut map : A) -> Result<View, A::Error> {
+    let (mut sig, mut data) = (None, None);
+    while let Some(key) = map.next_key::<String>()? {
+      match key.as_str() {
+        "sig"  => sig  = Some(map.next_value::<Option<RawOwned>>()?),
+        "data" => data = Some(map.next_value::<RawOwned>()?),
+        _      => { map.next_value::<de::IgnoredAny>()?; }
+      }
+    }
+    Ok(_view(sig . flatten(), data . ok_or_else(|| de::Error::missing_field("data"))?))
+  }
+}
+
+// *private* the header is the sig, however long
+fn _view(sig : Option<RawOwned>, data : RawOwned) -> View {
+  let sig = sig . map(|x| x.0) . unwrap_or_default();
+  View::with_config(Calldata::from_bytes(&[sig.as_slice(), &data.0].concat()), ViewConfig::new().header(sig.len()))
+}
+
+// *private* `0x` hex for people, bytes for machines
+fn _hex_or_bytes<S: Serializer>(bytes : &[u8], s : S) -> Result<S::Ok, S::Error> {
+  match s.is_human_readable() {
+    true  => s.serialize_str(&("0x".to_owned() + &bytes_to_hex(bytes))),
+    false => s.serialize_bytes(bytes)
+  }
+}
+
+// *private* hex in a string, prefix optional, or bytes however they come
+fn _read<'de, D: Deserializer<'de>>(d : D) -> Result<Vec<u8>, D::Error> {
+  match d.is_human_readable() {
+    true  => d.deserialize_str(HexOrBytes),
+    false => d.deserialize_byte_buf(HexOrBytes)
+  }
+}
+
+// *private*
+struct HexOrBytes;
+
+impl<'de> Visitor<'de> for HexOrBytes {
+  type Value = Vec<u8>;
+
+  fn expecting(&self, f : &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "a hex string or bytes")
+  }
+
+  fn visit_str<E: de::Error>(self, v : &str) -> Result<Vec<u8>, E> {
+    parse_hex(v) . map_err(E::custom)
+  }
+
+  fn visit_bytes<E: de::Error>(self, v : &[u8]) -> Result<Vec<u8>, E> { Ok(v.to_vec()) }
+
+  fn visit_byte_buf<E: de::Error>(self, v : Vec<u8>) -> Result<Vec<u8>, E> { Ok(v) }
+  // formats without a bytes type send a list of them
+  fn visit_seq<A: SeqAccess<'de>>(self, mut seq : A) -> Result<Vec<u8>, A::Error> {
+    let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+    while let Some(b) = seq.next_element::<u8>()? { out.push(b) }
+    Ok(out)
+  }
+}
+
+const VIEW_FIELDS : &[&str] = &["sig", "data"];
+
+use crate::bai::con::bytes_to_hex;
+
+/*
+   End of serial.
+  //////////////////////////////////////////////////////////////////////// */
+/* --------------------------------------------------------------------------
+                                                      MIT License 2024 Maka  */
@@ -0,0 +1,263 @@
+/* ----------------------------------------------------------------------------
+   @title  : template - call shapes with named holes
+   @author : Maka
+
+   @notice : the same swap over and over with a new recipient, amount or
+             deadline. A template is annotated hex with holes in it, filled
+             from a map of values to make `Calldata`. Templates keep as
+             plain text, several to a file, the same way the registry does.
+
+             template transfer
+             selector transfer(address,uint256)   // or the hex, a9059cbb
+             {recipient:address}
+             {amount:uint256}
+
+             Hex and holes can share a line, `//` and `#` start a comment.
+             A hole is a word of abi encoding, any static type. Marked
+             `{name:type:packed}` it takes only its own bytes instead, for
+             elementary types, `bytes` and `string`. A name used twice takes
+             the same value in both places.
+   errors  : a line that can't be read says which. Filling names the hole
+             that is missing or can't hold its value, and any value that
+             has no hole to go in.
+// --------------------------------------------------------------------------*/
+
+use crate::prelude::*;
+
+use alloc::collections::BTreeMap;
+use core::borrow::Borrow;
+
+use crate::Calldata;
+use crate::abi::{ self, ParamType, Value };
+use crate::error::KawalaError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Part {
+  Hex (Vec<u8>),
+  Hole { name : String, kind : ParamType, packed : bool }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+  pub name  : String,
+  pub parts : Vec<Part>
+}
+
+impl Template {
+
+  // parse a single template, the `template` line optional
+  pub fn parse(text : &str) -> Result<Template, KawalaError> {
+    let mut template = Template::new("");
+    for (i, line) in text . lines() . enumerate() {
+      let line = _clean(line);
+      match _header(line) {
+        Some(name) if template.name.is_empty() && template.parts.is_empty() => template.name = name.to_owned(),
+        Some(_)                 => return Err(KawalaError::Template { line : i + 1, text : line.to_owned() }),
+        None if line.is_empty() => continue,
+        None                    => template._line(line, i + 1)?
+      }
+    }
+    Ok(template)
+  }
+
+  pub fn new(name : &str) -> Self {
+    Template { name : name.to_owned(), parts : Vec::new() }
+  }
+  // holes in order of first use, name, type and whether packed
+  pub fn holes(&self) -> Vec<(&str, &ParamType, bool)> {
+    let mut out : Vec<(&str, &ParamType, bool)> = Vec::new();
+    for part in &self.parts {
+      if let Part::Hole { name, kind, packed } = part {
+        if !out . iter() . any(|(n, ..)| n == name) { out.push((name, kind, *packed)) }
+      }
+    }
+    out
+  }
+  // fill every hole from text values, as `Value::parse` reads them. Every
+  // hole needs a value and every value needs a hole
+  pub fn fill<K, V>(&self, values : &BTreeMap<K, V>) -> Result<Calldata, KawalaError>
+  where K : Borrow<str> + Ord, V : AsRef<str> {
+    let holes = self.holes();
+    if let Some(name) = values . keys() . find(|k| !holes . iter() . any(|(n, ..)| *n == (*k).borrow())) {
+      return Err(KawalaError::Unused(name.borrow().to_owned()))
+    }
+    let mut out = Vec::new();
+    for part in &self.parts {
+      match part {
+        Part::Hex(bytes) => out . extend_from_slice(bytes),
+        Part::Hole { name, kind, packed } => {
+          let text = values . get(name.as_str()) . ok_or_else(|| KawalaError::Missing(name.clone()))?;
+          let mistyped = || KawalaError::Mistyped { name : name.clone(), kind : kind.canonical(), value : text.as_ref().to_owned() };
+          let value = Value::parse(kind, text.as_ref()) . map_err(|_| mistyped())?;
+          out . extend(match packed {
+            true  => _packed(kind, &value) . ok_or_else(mistyped)?,
+            false => abi::encode_value(kind, &value) . map_err(|_| mistyped())?
+          })
+        }
+      }
+    }
+    Ok(Calldata::from(out))
+  }
+  // plain text form, as it parses
+  pub fn to_text(&self) -> String {
+    let mut out = format!("template {}\n", self.name);
+    for part in &self.parts {
+      match part {
+        Part::Hex(bytes) => out += &bytes_to_hex(bytes),
+        Part::Hole { name, kind, packed : false } => out += &format!("{{{}:{}}}", name, kind.canonical()),
+        Part::Hole { name, kind, packed : true }  => out += &format!("{{{}:{}:packed}}", name, kind.canonical())
+      }
+      out += "\n"
+    }
+    out
+  }
+
+  // *private* a line's worth of hex and holes
+  fn _line(&mut self, line : &str, at : usize) -> Result<(), KawalaError> {
+    let err = |text : &str| KawalaError::Template { line : at, text : text.to_owned() };
+    if let Some(sig) = line.strip_prefix("selector ") {
+      let sig = sig.trim();
+      return match sig.contains('(') {
+        true  => {
+          // canonical first, `transfer(address, uint)` is `transfer(address,uint256)`
+          let canonical = abi::canonical_signature(sig) . map_err(|_| err(sig))?;
+          self.parts.push(Part::Hex(selector(&canonical).to_vec())); Ok(())
+        },
+        false => self._line(sig, at)
+      }
+    }
+    for token in line . split_whitespace() {
+      match token . strip_prefix('{') . and_then(|t| t.strip_suffix('}')) {
+        Some(hole) => {
+          let mut fields = hole.split(':');
+          let name   = fields . next() . unwrap_or("") . trim();
+          let kind   = fields . next() . and_then(|t| ParamType::parse(t).ok()) . ok_or_else(|| err(token))?;
+          let packed = match fields . next() { None => false, Some("packed") => true, Some(_) => return Err(err(token)) };
+          let usable = match packed { true => _packable(&kind), false => !kind.is_dynamic() };
+          let clash  = self.holes() . iter() . any(|(n, k, p)| *n == name && (**k != kind || *p != packed));
+          if name.is_empty() || !usable || clash { return Err(err(token)) }
+          self.parts.push(Part::Hole { name : name.to_owned(), kind, packed })
+        },
+        None => {
+          let bytes = parse_hex(token) . map_err(|_| err(token))?;
+          match self.parts.last_mut() {
+            Some(Part::Hex(last)) => last . extend(bytes),
+            _                     => self.parts.push(Part::Hex(bytes))
+          }
+        }
+      }
+    }
+    Ok(())
+  }
+}
+
+/* ----------------------------------------------------------------------------
+ Templates
+-----------------------------------------------------------------------------*/
+  ///////////////////////////////////////////////////////////////////////////
+
+  /* a named set, read from and written to plain text. Each starts with a
+     `template name` line and runs to the next */
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Templates {
+  map : BTreeMap<String, Template>
+}
+
+#[allow(clippy::unused_unit)]
+impl Templates {
+
+  pub fn new() -> Self { Self::default() }
+
+  pub fn from_text(text : &str) -> Result<Self, KawalaError> {
+    let mut set = Self::new(); let mut current : Option<Template> = None;
+    for (i, line) in text . lines() . enumerate() {
+      let line = _clean(line);
+      if let Some(name) = _header(line) {
+        if let Some(done) = current.take() { set.insert(done) }
+        current = Some(Template::new(name)); continue
+      }
+      if line.is_empty() { continue }
+      match current.as_mut() {
+        Some(t) => t._line(line, i + 1)?,
+        None    => return Err(KawalaError::Template { line : i + 1, text : line.to_owned() })
+      }
+    }
+    if let Some(done) = current { set.insert(done) }
+    Ok(set)
+  }
+
+  pub fn to_text(&self) -> String {
+    self.map . values() . map(|t| t.to_text()) . collect::<Vec<_>>() . join("\n")
+  }
+  // add or replace by name
+  pub fn insert(&mut self, template : Template) -> () {
+    self.map.insert(template.name.clone(), template);
+  }
+
+  pub fn get(&self, name : &str) -> Option<&Template> { self.map.get(name) }
+  // template names, in order
+  pub fn names(&self) -> Vec<&str> { self.map . keys() . map(|k| k.as_str()) . collect() }
+
+  pub fn len(&self) -> usize { self.map.len() }
+
+  pub fn is_empty(&self) -> bool { self.map.is_empty() }
+  // fill a template by name
+  pub fn fill<K, V>(&self, name : &str, values : &BTreeMap<K, V>) -> Result<Calldata, KawalaError>
+  where K : Borrow<str> + Ord, V : AsRef<str> {
+    self.get(name) . ok_or_else(|| KawalaError::Missing(name.to_owned()))? . fill(values)
+  }
+
+  #[cfg(feature = "std")]
+  pub fn save<P: AsRef<std::path::Path>>(&self, path : P) -> std::io::Result<()> {
+    std::fs::write(path, self.to_text())
+  }
+  // a file that reads but doesn't parse is `InvalidData`
+  #[cfg(feature = "std")]
+  pub fn load<P: AsRef<std::path::Path>>(path : P) -> std::io::Result<Self> {
+    let text = std::fs::read_to_string(path)?;
+    Self::from_text(&text) . map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+  }
+}
+
+// *private* a line without its comment
+fn _clean(line : &str) -> &str {
+  line . split("//") . next() . unwrap_or("") . split('#') . next() . unwrap_or("") . trim()
+}
+// *private* the name on a `template name` line
+fn _header(line : &str) -> Option<&str> {
+  match line == "template" { true => Some(""), false => line.strip_prefix("template ") . map(|n| n.trim()) }
+}
+// *private* types that have a packed form
+fn _packable(kind : &ParamType) -> bool {
+  matches!(kind, ParamType::Address | ParamType::Bool | ParamType::Uint(_) | ParamType::Int(_)
+    | ParamType::FixedBytes(_) | ParamType::Bytes | ParamType::String)
+}
+// *private* a value's own bytes, `abi.encodePacked` style
+fn _packed(kind : &ParamType, value : &Value) -> Option<Vec<u8>> {
+  match (kind, value) {
+    (ParamType::Bytes, Value::Bytes(b))   => Some(b.clone()),
+    (ParamType::String, Value::String(s)) => Some(s.as_bytes().to_vec()),
+    _ => {
+      let word = value.encode_word(kind) . ok()?;
+      match kind {
+        ParamType::Address            => Some(word[12..].to_vec()),
+        ParamType::Bool               => Some(word[31..].to_vec()),
+        ParamType::Uint(bits) |
+        ParamType::Int(bits)          => Some(word[32 - bits / 8..].to_vec()),
+        ParamType::FixedBytes(n)      => Some(word[..*n].to_vec()),
+        _                             => None
+      }
+    }
+  }
+}
+
+use crate::bai::con::bytes_to_hex;
+use crate::error::parse_hex;
+use crate::kec256::hash::selector;
+
+/*
+   End of template.
+  //////////////////////////////////////////////////////////////////////// */
+/* --------------------------------------------------------------------------
+                                                      MIT License 2024 Maka  */
@@ -0,0 +1,221 @@
+//------------------------------------------- ABI ----------------------------------------------//
+// ----------------------------------------------------------------------------------------------//
+#[cfg(test)]
+mod abi {
+  use kawala::abi::{ self, ParamType, Param, Value, AbiError, Abi, Function, Event, Link };
+  use kawala::bai::con::hex_to_bytes;
+
+// ----------------------------------------- TYPES ----------------------------------------------//
+
+  #[test]
+  fn parse_elementary() {
+    assert_eq!(ParamType::parse("uint").unwrap(),    ParamType::Uint(256));
+    assert_eq!(ParamType::parse("int24").unwrap(),   ParamType::Int(24));
+    assert_eq!(ParamType::parse("bytes32").unwrap(), ParamType::FixedBytes(32));
+    assert!(ParamType::parse("uint7").is_err());
+    assert!(ParamType::parse("bytes33").is_err());
+  }
+
+  #[test]
+  fn parse_nested_canonical() {
+    let kind = ParamType::parse("tuple(address maker, uint256[2][] amounts)[]").unwrap();
+    assert_eq!(kind.canonical(), "(address,uint256[2][])[]");
+    assert!(kind.is_dynamic());
+    let kind = ParamType::parse("(address,uint256)[3]").unwrap();
+    assert!(!kind.is_dynamic());
+    assert_eq!(kind.head_len(), 192);
+  }
+
+  #[test]
+  fn oversized_fixed_arrays() {
+    // a head that can't be counted is refused rather than overflowing
+    assert!(ParamType::parse("uint256[1152921504606846976]").is_err());
+    assert!(ParamType::parse("bytes[1152921504606846976]").is_err());
+    assert!(ParamType::parse("(uint256[4611686018427387904],uint256[4611686018427387904])").is_err());
+    assert!(Function::parse("f(uint256[1152921504606846976])").is_err());
+    let kind = ParamType::FixedArray(Box::new(ParamType::Uint(256)), usize::MAX);
+    assert_eq!(kind.try_head_len(), None);
+    assert_eq!(kind.head_len(), usize::MAX);
+    assert_eq!(ParamType::parse("uint256[1024]").unwrap().head_len(), 32768);
+  }
+
+  #[test]
+  fn oversized_fixed_arrays_json() {
+    // the same from an abi file, tuples through their components
+    let json = |ty : &str| format!(r#"[{{"type":"function","name":"f","inputs":[{{"name":"a","type":"{}","components":[{{"type":"uint256[4611686018427387904]"}},{{"type":"uint256[4611686018427387904]"}}]}}],"outputs":[]}}]"#, ty);
+    assert!(Abi::from_json(&json("uint256[1152921504606846976]")).is_err());
+    assert!(Abi::from_json(&json("tuple")).is_err());
+    assert!(Abi::from_json(&json("uint256[1024]")).is_ok());
+    assert!(kawala::Contract::from_json(&json("uint256[1152921504606846976]")).is_err());
+  }
+
+  #[test]
+  fn json_nesting_capped() {
+    // deep enough to take the stack if the reader followed it down
+    assert!(Abi::from_json(&"[".repeat(200_000)).is_err());
+    assert!(Abi::from_json(&"[{\"a\":".repeat(100_000)).is_err());
+    assert!(kawala::json::parse(&("[".repeat(300) + &"]".repeat(300))).is_none());
+    assert!(kawala::json::parse(&("[".repeat(200) + &"]".repeat(200))).is_some());
+    // depth is per path, siblings don't add up
+    let wide = format!("[{}]", vec!["[[[]]]"; 500].join(","));
+    assert!(kawala::json::parse(&wide).is_some());
+  }
+
+  #[test]
+  fn parse_param_markers() {
+    let p = Param::parse("address indexed from").unwrap();
+    assert_eq!((p.name.as_str(), p.indexed, p.kind), ("from", true, ParamType::Address));
+    assert!(Param::parse("address from to").is_err());
+  }
+
+// ---------------------------------------- DECODING --------------------------------------------//
+
+  #[test]
+  fn decode_static() {
+    let data = hex_to_bytes(&[
+      "000000000000000000000000000000000022d473030f116ddee9f6b43ac78ba3",
+      "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff85"
+    ].concat());
+    let out = abi::decode(&[ParamType::Address, ParamType::Int(256)], &data).unwrap();
+    assert_eq!(out[0].to_string(), "0x000000000022d473030f116ddee9f6b43ac78ba3");
+    assert_eq!(out[1].to_string(), "-123");
+  }
+
+  #[test]
+  fn decode_dynamic() {
+    // (string, uint256[]) => ("hi", [1, 2])
+    let data = hex_to_bytes(&[
+      "0000000000000000000000000000000000000000000000000000000000000040",
+      "0000000000000000000000000000000000000000000000000000000000000080",
+      "0000000000000000000000000000000000000000000000000000000000000002",
+      "6869000000000000000000000000000000000000000000000000000000000000",
+      "0000000000000000000000000000000000000000000000000000000000000002",
+      "0000000000000000000000000000000000000000000000000000000000000001",
+      "0000000000000000000000000000000000000000000000000000000000000002"
+    ].concat());
+    let kinds = [ParamType::String, ParamType::parse("uint256[]").unwrap()];
+    let out   = abi::decode(&kinds, &data).unwrap();
+    assert_eq!(out[0], Value::String("hi".to_owned()));
+    assert_eq!(out[1].to_string(), "[1, 2]");
+    assert_eq!(abi::encode(&kinds, &out).unwrap(), data);
+  }
+
+  #[test]
+  fn decode_bad_offset() {
+    let data = hex_to_bytes(&"ff".repeat(32));
+    assert_eq!(abi::decode(&[ParamType::Bytes], &data), Err(AbiError::BadOffset(0)));
+    assert_eq!(abi::decode(&[ParamType::Address, ParamType::Bool], &data[..32]), Err(AbiError::OutOfBounds(32)));
+  }
+
+// ------------------------------------- HUMAN READABLE -----------------------------------------//
+
+  #[test]
+  fn human_function() {
+    let f = Function::parse(
+      "function swapExactTokensForTokens(uint256 amountIn, uint256 amountOutMin, \
+       address[] calldata path, address to, uint256 deadline) external returns (uint256[] memory amounts)"
+    ).unwrap();
+    assert_eq!(f.signature(), "swapExactTokensForTokens(uint256,uint256,address[],address,uint256)");
+    assert_eq!(f.selector(), [0x38, 0xed, 0x17, 0x39]);
+    assert_eq!(f.inputs[2].name, "path");
+    assert_eq!(f.outputs[0].name, "amounts");
+    assert_eq!(f.mutability, "nonpayable");
+    assert_eq!(Function::parse("balanceOf(address owner) view returns (uint256)").unwrap().mutability, "view");
+    assert!(Function::parse("function f(uint256) returns uint256").is_err());
+    assert!(Function::parse("function f(uint256) sometimes").is_err());
+  }
+
+  #[test]
+  fn human_abi() {
+    let abi = Abi::from_human(&[
+      "constructor(address factory) payable",
+      "function fill(tuple(address maker, uint256 amount)[] orders) payable",
+      "event Transfer(address indexed from, address indexed to, uint256 value)",
+      "error Expired(uint256 deadline)",
+      "deposit()"
+    ]).unwrap();
+    assert_eq!(abi.functions.len(), 2);
+    assert_eq!(abi.event("Transfer"), Some(&Event::parse("Transfer(address indexed from, address indexed to, uint256 value)").unwrap()));
+    assert_eq!(abi.error("Expired").unwrap().signature(), "Expired(uint256)");
+    assert_eq!(abi.constructor.as_ref().unwrap().mutability, "payable");
+    assert_eq!(abi.function("fill").unwrap().signature(), "fill((address,uint256)[])");
+  }
+
+// ----------------------------------------- VALUES ---------------------------------------------//
+
+  #[test]
+  fn value_parse_and_fit() {
+    let u8t = ParamType::Uint(8);
+    assert_eq!(Value::parse(&u8t, "255").unwrap().to_string(), "255");
+    assert!(Value::parse(&u8t, "256").is_err());
+    assert_eq!(Value::parse(&ParamType::Int(8), "-128").unwrap().to_string(), "-128");
+    assert!(Value::parse(&ParamType::Int(8), "-129").is_err());
+    let list = ParamType::parse("(address,uint256)[]").unwrap();
+    let v    = Value::parse(&list, &format!("[(0x{}, 1), (0x{}, 0x02)]", "ab".repeat(20), "cd".repeat(20))).unwrap();
+    assert_eq!(v.to_string(), format!("[(0x{}, 1), (0x{}, 2)]", "ab".repeat(20), "cd".repeat(20)));
+    assert!(Value::parse(&ParamType::Address, "0x1234").is_err());
+    assert!(Value::Uint([0xff;32]).encode_word(&ParamType::Address).is_err());
+  }
+
+  #[test]
+  fn value_parse_signed_bounds() {
+    let i8t = ParamType::Int(8);
+    assert_eq!(Value::parse(&i8t, "127").unwrap().to_string(), "127");
+    assert!(Value::parse(&i8t, "128").is_err());
+    assert!(Value::parse(&i8t, "0x80").is_err());
+    assert_eq!(Value::parse(&i8t, "-0").unwrap().to_string(), "0");
+    // 2^255, the top of an int256 is one short of it, the bottom its negation
+    let half = "57896044618658097711785492504343953926634992332820282019728792003956564819968";
+    let top  = "57896044618658097711785492504343953926634992332820282019728792003956564819967";
+    let i256 = ParamType::Int(256);
+    assert!(Value::parse(&i256, half).is_err());
+    assert_eq!(Value::parse(&i256, top).unwrap().to_string(), top);
+    assert_eq!(Value::parse(&i256, &format!("-{}", half)).unwrap().to_string(), format!("-{}", half));
+    assert!(Value::parse(&i256, "-57896044618658097711785492504343953926634992332820282019728792003956564819969").is_err());
+  }
+
+// ----------------------------------------- LINKS ----------------------------------------------//
+
+  #[test]
+  fn links_typed_and_inferred() {
+    // (string, uint256[]) => ("hi", [1, 2])
+    let data = hex_to_bytes(&[
+      "0000000000000000000000000000000000000000000000000000000000000040",
+      "0000000000000000000000000000000000000000000000000000000000000080",
+      "0000000000000000000000000000000000000000000000000000000000000002",
+      "6869000000000000000000000000000000000000000000000000000000000000",
+      "0000000000000000000000000000000000000000000000000000000000000002",
+      "0000000000000000000000000000000000000000000000000000000000000001",
+      "0000000000000000000000000000000000000000000000000000000000000002"
+    ].concat());
+    let params = abi::parse_params("string s, uint256[] xs").unwrap();
+    let links  = abi::links(&params, &data).unwrap();
+    assert_eq!(links, [
+      Link::Offset { at : 0,  base : 0, target : 64 },
+      Link::Length { at : 64, count : 2, unit : 1 },
+      Link::Offset { at : 32, base : 0, target : 128 },
+      Link::Length { at : 128, count : 2, unit : 32 }
+    ]);
+    let mut inferred = abi::infer_links(&data);
+    inferred.sort_by_key(|l| l.at());
+    let mut typed = links.clone();
+    typed.sort_by_key(|l| l.at());
+    assert_eq!(inferred, typed);
+  }
+
+  #[test]
+  fn relink_around_edits() {
+    let links = [
+      Link::Offset { at : 0,  base : 0, target : 64 },
+      Link::Length { at : 64, count : 2, unit : 1 },
+      Link::Offset { at : 32, base : 0, target : 128 },
+      Link::Length { at : 128, count : 2, unit : 32 }
+    ];
+    // a word at the end of the string's padding grows it, the array moves
+    assert_eq!(abi::relink(&links, 128, 1), [(0, 64), (64, 34), (32, 160), (160, 2)]);
+    // an element removed from the array
+    assert_eq!(abi::relink(&links, 160, -1), [(0, 64), (64, 2), (32, 128), (128, 1)]);
+    // a word in front of the head moves every word, offsets still hold
+    assert_eq!(abi::relink(&links, 0, 1), [(32, 96), (96, 2), (64, 160), (160, 2)]);
+  }
+}
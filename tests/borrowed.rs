@@ -0,0 +1,113 @@
+//----------------------------------------- BORROWED -------------------------------------------//
+// ----------------------------------------------------------------------------------------------//
+#[cfg(test)]
+mod borrowed {
+  use kawala::{ View, ViewRef, Calldata, WithSig, ViewConfig, KawalaError };
+
+  // approve(spender, amount) and a 3 byte tail
+  fn call() -> Vec<u8> {
+    [vec![0x09, 0x5e, 0xa7, 0xb3], vec![0u8;12], vec![0xaa;20], vec![0u8;31], vec![0x10], vec![1, 2, 3]].concat()
+  }
+
+// ----------------------------------------- READS ----------------------------------------------//
+
+  #[test]
+  fn reads_match_view() {
+    let data = call();
+    let view = View::new(Calldata::from_bytes(&data), WithSig::True);
+    let vref = ViewRef::new(&data, WithSig::True);
+    assert_eq!(vref.sig(), view.sig());
+    assert_eq!(vref.data(), view.data());
+    assert_eq!(vref.hex_0x(), view.hex_0x());
+    assert_eq!(vref.word_count(), 3);
+    assert_eq!(vref.page(), view.page());
+    assert_eq!(vref.word(100), view.word(100));
+    assert_eq!(vref.words(1, 9), view.words(1, 9));
+    assert_eq!(vref.read_bytes(60, 10), view.read_bytes(60, 10));
+    assert_eq!(vref.word32(2), view.__word(2).as_bytes32());
+    assert_eq!(vref.__words(0, 2), &data[4..68]);
+    assert_eq!(vref, view);
+    assert_eq!(view, vref);
+    assert_eq!(ViewRef::from_calldata(&Calldata::from_bytes(&data), WithSig::False).word_count(), 3);
+  }
+
+  #[test]
+  fn borrows_input() {
+    let data = call();
+    let vref = ViewRef::new(&data, WithSig::True);
+    assert!(std::ptr::eq(vref.__word(1).as_ptr(), data[36..].as_ptr()));
+    assert!(std::ptr::eq(vref.sig_bytes().as_ptr(), data.as_ptr()));
+    let (i, offset, word) = vref.iter() . last() . unwrap();
+    assert_eq!((i, offset, word), (2, 64, &data[68..]));
+    assert_eq!(vref.nonzero() . count(), 3);
+    assert_eq!(vref.find(|w| w[31] == 0x10) . map(|x| x.0), Some(1));
+  }
+
+  // runs of words, each as one slice of the input
+  #[test]
+  fn windows_and_chunks() {
+    let data = call();
+    let vref = ViewRef::new(&data, WithSig::True);
+    let view = View::new(Calldata::from_bytes(&data), WithSig::True);
+    let joined = |run : &[kawala::Word]| run . iter() . flat_map(|w| w.bytes().to_vec()) . collect::<Vec<u8>>();
+    for n in 0..5 {
+      let a = vref.windows(n) . map(|(i, o, w)| (i, o, w.to_vec())) . collect::<Vec<_>>();
+      let b = view.windows(n) . map(|(i, o, w)| (i, o, joined(w))) . collect::<Vec<_>>();
+      assert_eq!(a, b, "windows({})", n);
+      let a = vref.chunks_of(n) . map(|(i, o, w)| (i, o, w.to_vec())) . collect::<Vec<_>>();
+      let b = view.chunks_of(n) . map(|(i, o, w)| (i, o, joined(w))) . collect::<Vec<_>>();
+      assert_eq!(a, b, "chunks_of({})", n);
+    }
+    let (i, o, run) = vref.chunks_of(2) . last() . unwrap();
+    assert_eq!((i, o, run), (2, 64, &data[68..]));
+    // uneven records go by the config
+    let data   = [vec![0x01], vec![0xaa;20], vec![0x11;32], vec![0xbb;20]].concat();
+    let config = ViewConfig::new().header(1).strides(&[20, 32]);
+    let vref   = ViewRef::with_config(&data, &config);
+    assert_eq!(vref.windows(2) . map(|(i, o, w)| (i, o, w.len())) . collect::<Vec<_>>(), [(0, 0, 52), (1, 20, 52)]);
+  }
+
+  #[test]
+  fn empty_and_checked() {
+    // an empty page clamps to a zero word, as `View` does
+    let vref = ViewRef::new(&[0xab, 0xcd], WithSig::True);
+    let view = View::new(Calldata::from_bytes(&[0xab, 0xcd]), WithSig::True);
+    assert_eq!((vref.sig(), vref.word_count(), vref.word(0)), ("abcd".to_owned(), 0, "00".repeat(32)));
+    assert_eq!((vref.word(5), vref.__word(0)), (view.word(5), view.__word(0).bytes()));
+    assert_eq!(vref.windows(1) . count() + vref.chunks_of(1) . count(), 0);
+    let data = call();
+    let vref = ViewRef::new(&data, WithSig::True);
+    assert_eq!(vref.__try_word(3), Err(KawalaError::OutOfBounds { index : 3, len : 3 }));
+    assert_eq!(vref.try_word(1), Ok(vref.word(1)));
+    assert_eq!(vref.try_read_bytes(60, 20), Err(KawalaError::ByteOutOfBounds { offset : 80, len : 67 }));
+    assert_eq!(vref.try_read_bytes(64, 3), Ok(&[1u8, 2, 3][..]));
+  }
+
+// -------------------------------------- CONFIG / OWNED ----------------------------------------//
+
+  #[test]
+  fn with_config() {
+    // a command byte over 20 byte records with a 32 byte amount
+    let data   = [vec![0x01], vec![0xaa;20], vec![0x11;32], vec![0xbb;20]].concat();
+    let config = ViewConfig::new().header(1).strides(&[20, 32]);
+    let vref   = ViewRef::with_config(&data, &config);
+    let view   = View::with_config(Calldata::from_bytes(&data), config.clone());
+    assert_eq!(vref.word_count(), 3);
+    assert_eq!(vref.page(), view.page());
+    assert_eq!(vref.__word(1), &[0x11;32]);
+    assert_eq!(vref.iter() . map(|x| x.1) . collect::<Vec<_>>(), [0, 20, 52]);
+    assert_eq!(vref.to_view(), view);
+    assert_eq!(vref.to_view().config(), &config);
+  }
+
+  #[test]
+  fn to_view_edits() {
+    let data = call();
+    let vref = ViewRef::new(&data, WithSig::True);
+    let mut view : View = vref.into();
+    assert_eq!(view.config(), &ViewConfig::new());
+    view.clear(0);
+    assert_ne!(vref, view);
+    assert_eq!(vref.bytes(), call().as_slice());
+  }
+}
@@ -0,0 +1,116 @@
+//----------------------------------------- BUILDER --------------------------------------------//
+// ----------------------------------------------------------------------------------------------//
+#[cfg(test)]
+mod builder {
+  use kawala::{ Calldata, View, WithSig, Render, KawalaError };
+  use kawala::builder::Segment;
+  use kawala::kwl32::util::{ from_dec32, neg32 };
+
+  const USDC : &str = "2791Bca1f2de4661ED88A30C99A7a9449Aa84174";
+
+  fn seg(label : &str, offset : usize, len : usize, packed : bool) -> Segment {
+    Segment { label : label.to_owned(), offset, len, packed }
+  }
+
+// ---------------------------------------- ALIGNED ---------------------------------------------//
+
+  #[test]
+  fn abi_call() {
+    let built = Calldata::builder()
+      . selector("transfer(address,uint256)")
+      . address(USDC) . named("to")
+      . uint(1000)    . named("amount")
+      . build();
+    let expect = "0xa9059cbb".to_owned() + &"0".repeat(24) + &USDC.to_lowercase() + &"0".repeat(61) + "3e8";
+    assert_eq!(built, Calldata::from_hex(&expect));
+    assert_eq!(Calldata::builder().selector("a9059cbb").build(), Calldata::from_hex("a9059cbb"));
+    assert_eq!(Calldata::builder().address("0xabc").build().hex(), "0".repeat(61) + "abc");
+  }
+
+  #[test]
+  fn aligned_values() {
+    let b = Calldata::builder() . int(-2) . boolean(true) . word("0x3e8") . word_from_bytes(&[1u8;40]);
+    let view = View::new(b.build(), WithSig::False);
+    assert_eq!(view.word(0), "ff".repeat(31) + "fe");
+    assert_eq!(view.word(1), "0".repeat(63) + "1");
+    assert_eq!(view.word(2), "0".repeat(61) + "3e8");
+    assert_eq!(view.word(3), "01".repeat(32));
+    assert!(b.layout() . iter() . all(|s| !s.packed && s.len == 32));
+  }
+
+  // spacing and `uint` aliases don't change the selector, junk is refused
+  #[test]
+  fn selector_canonical() {
+    let plain = Calldata::builder() . selector("transfer(address,uint256)") . build();
+    assert_eq!(plain, Calldata::from_hex("a9059cbb"));
+    assert_eq!(Calldata::builder() . selector("transfer(address, uint256)") . build(), plain);
+    assert_eq!(Calldata::builder() . selector("transfer(address,uint)") . build(), plain);
+    assert_eq!(Calldata::builder() . selector("function transfer(address to, uint amount)") . build(), plain);
+    assert_eq!(Calldata::builder() . selector("transfer(adress,uint)") . build(), Calldata::from_hex("00000000"));
+    assert_eq!(Calldata::builder() . try_selector("transfer(address, uint)") . unwrap() . build(), plain);
+    assert!(matches!(Calldata::builder() . try_selector("transfer(adress,uint)"), Err(KawalaError::Abi(_))));
+    assert_eq!(Calldata::builder() . try_selector("0xa9059cbb") . unwrap() . build(), plain);
+    assert_eq!(Calldata::builder() . try_selector("a9059c") . unwrap_err(), KawalaError::Length { expected : 4, found : 3 });
+  }
+
+  // full width numbers, past what a u128 holds
+  #[test]
+  fn aligned_256() {
+    let max = from_dec32("115792089237316195423570985008687907853269984665640564039457584007913129639935").unwrap();
+    let b = Calldata::builder() . uint256(&max) . uint256(&from_dec32("1000").unwrap()) . int256(&neg32(&max));
+    let view = View::new(b.build(), WithSig::False);
+    assert_eq!(view.word(0), "ff".repeat(32));
+    assert_eq!(view.word(1), "0".repeat(61) + "3e8");
+    assert_eq!(view.word(2), "0".repeat(63) + "1");
+    assert_eq!(b.layout() . iter() . map(|s| s.label.as_str()) . collect::<Vec<_>>(), ["uint", "uint", "int"]);
+    assert_eq!(Calldata::builder() . uint256(&from_dec32("1000").unwrap()) . build(), Calldata::builder() . uint(1000) . build());
+  }
+
+// ----------------------------------------- PACKED ---------------------------------------------//
+
+  #[test]
+  fn packed_stream() {
+    // the `basic_stream` example, command bytes, a 10 byte amount and an address
+    let b = Calldata::builder()
+      . byte(0x01) . byte(0xff)
+      . uint_packed(42069123456789876543210, 10) . named("amount")
+      . address_packed(USDC);
+    assert_eq!(b.build().hex(), ("01ff08E8925E5C2E7DE78EEA".to_owned() + USDC).to_lowercase());
+    assert_eq!(b.len(), 32);
+    assert_eq!(b.layout(), [
+      seg("byte", 0, 1, true), seg("byte", 1, 1, true), seg("amount", 2, 10, true), seg("address", 12, 20, true)
+    ]);
+    assert_eq!(Calldata::builder().hex_packed("abcd").bytes_packed(&[1]).build().bytes(), [0xab, 0xcd, 1]);
+  }
+
+  #[test]
+  fn mixed_and_padding() {
+    // a packed byte pushes the next aligned word to the boundary
+    let b = Calldata::builder() . byte(7) . uint(1) . bytes_packed(b"hi") . pad_to_word() . pad_to_word();
+    assert_eq!(b.len(), 96);
+    assert_eq!(b.layout(), [
+      seg("byte", 0, 1, true), seg("pad", 1, 31, true), seg("uint", 32, 32, false),
+      seg("bytes", 64, 2, true), seg("pad", 66, 30, true)
+    ]);
+    assert_eq!(Calldata::builder().pad_to_word().len(), 0);
+  }
+
+// ----------------------------------------- VIEWS ----------------------------------------------//
+
+  #[test]
+  fn annotated_view() {
+    let view = Calldata::builder()
+      . selector("a9059cbb")
+      . address(USDC) . named("to")
+      . byte(1) . byte(2) . named("flag")
+      . build_view();
+    assert_eq!(view.sig(), "a9059cbb");
+    assert_eq!((view.note(0), view.note(1), view.note(2)), (Some("to"), Some("byte, flag"), None));
+    let out = Render::new().offset(false).render(&view);
+    assert_eq!(out.lines().nth(2), Some("1  0102  // byte, flag"));
+    // the render's own notes still win
+    assert!(Render::new().annotate(0, "recipient").render(&view).contains("// recipient"));
+    let plain = Calldata::builder() . uint(5) . build_view();
+    assert_eq!((plain.sig(), plain.note(0)), (String::new(), Some("uint")));
+  }
+}
@@ -3,6 +3,7 @@
 #[cfg(test)]
 mod calldata { 
   use kawala::{ Calldata };
+  use std::collections::HashMap;
 // ----------------------------------- CREATION AND ACCESS --------------------------------------//
 
   #[test]
@@ -67,5 +68,19 @@ mod calldata {
     assert_eq!(call.len(), 64);
   }
 
-}
 
+// ---------------------------------------- STD TRAITS ------------------------------------------//
+
+  #[test]
+  fn std_traits() {
+    let a : Calldata = "0xa9059cbb".parse().unwrap();
+    let b = Calldata::from(&[0xa9u8, 0x05, 0x9c, 0xbb][..]);
+    assert_eq!(a, b);
+    assert_eq!(a.clone().as_ref(), [0xa9, 0x05, 0x9c, 0xbb]);
+    assert_eq!(Calldata::from(vec![1u8, 2]) . cmp(&Calldata::from(vec![1u8, 3])), std::cmp::Ordering::Less);
+    let mut seen = HashMap::new();
+    seen.insert(a, "transfer");
+    assert_eq!(seen.get(&b), Some(&"transfer"));
+    assert!("0x123".parse::<Calldata>().is_err());
+  }
+}
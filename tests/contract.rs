@@ -0,0 +1,115 @@
+//---------------------------------------- CONTRACT --------------------------------------------//
+// ----------------------------------------------------------------------------------------------//
+#[cfg(test)]
+mod contract {
+  use kawala::{ Contract, Log, View, Calldata, WithSig };
+  use kawala::abi::{ Abi, ParamType, Value };
+  use kawala::revert::Revert;
+  use kawala::bai::con::hex_to_bytes;
+
+  const ARTIFACT : &str = r#"{
+    "abi": [
+      { "type": "constructor", "stateMutability": "nonpayable",
+        "inputs": [ { "name": "factory", "type": "address", "internalType": "address" } ] },
+      { "type": "function", "name": "swapExactTokensForETHSupportingFeeOnTransferTokens",
+        "stateMutability": "nonpayable", "outputs": [],
+        "inputs": [
+          { "name": "amountIn",     "type": "uint256",   "internalType": "uint256" },
+          { "name": "amountOutMin", "type": "uint256",   "internalType": "uint256" },
+          { "name": "path",         "type": "address[]", "internalType": "address[]" },
+          { "name": "to",           "type": "address",   "internalType": "address" },
+          { "name": "deadline",     "type": "uint256",   "internalType": "uint256" } ] },
+      { "type": "function", "name": "fill", "stateMutability": "payable",
+        "inputs": [ { "name": "orders", "type": "tuple[]", "internalType": "struct Book.Order[]",
+          "components": [
+            { "name": "maker",  "type": "address", "internalType": "address" },
+            { "name": "amount", "type": "uint256", "internalType": "uint256" } ] } ],
+        "outputs": [ { "name": "filled", "type": "uint256", "internalType": "uint256" } ] },
+      { "type": "event", "name": "Transfer", "anonymous": false, "inputs": [
+          { "name": "from",  "type": "address", "indexed": true },
+          { "name": "to",    "type": "address", "indexed": true },
+          { "name": "value", "type": "uint256", "indexed": false } ] },
+      { "type": "error", "name": "Expired", "inputs": [ { "name": "deadline", "type": "uint256" } ] },
+      { "type": "receive", "stateMutability": "payable" }
+    ]
+  }"#;
+
+  const SWAP : &str = "0x791ac94700000000000000000000000000000000000000000000000000000000004c3f88000000000000000000000000000000000000000000000000000ac2d7237640f900000000000000000000000000000000000000000000000000000000000000a000000000000000000000000013a48c3e0a403b6cf1a59fbd600e284e620b37ed0000000000000000000000000000000000000000000000000000000065211d050000000000000000000000000000000000000000000000000000000000000002000000000000000000000000ff970a61a04b1ca14834a43f5de4533ebddb5cc800000000000000000000000082af49447d8a07e3bd95bd0d56f35241523fbab1";
+
+// ----------------------------------------- MODEL ----------------------------------------------//
+
+  #[test]
+  fn abi_from_json() {
+    let abi = Abi::from_json(ARTIFACT).unwrap();
+    assert_eq!((abi.functions.len(), abi.events.len(), abi.errors.len()), (2, 1, 1));
+    assert!(abi.constructor.is_some());
+    let fill = abi.function("fill").unwrap();
+    assert_eq!(fill.signature(), "fill((address,uint256)[])");
+    assert_eq!(fill.mutability, "payable");
+    assert_eq!(fill.inputs[0].internal.as_deref(), Some("struct Book.Order[]"));
+    match &fill.inputs[0].kind {
+      ParamType::Array(t) => match &**t {
+        ParamType::Tuple(ps) => assert_eq!(ps[1].name, "amount"),
+        other                => panic!("{:?}", other)
+      },
+      other => panic!("{:?}", other)
+    }
+  }
+
+  #[test]
+  fn abi_from_bad_json() {
+    assert!(Abi::from_json("{ \"abi\": 1 }").is_err());
+    assert!(Abi::from_json("[{ \"type\": \"function\", \"name\": \"f\", \"inputs\": [{ \"type\": \"uint7\" }] }]").is_err());
+  }
+
+// ---------------------------------------- DECODING --------------------------------------------//
+
+  #[test]
+  fn decode_call() {
+    let contract = Contract::from_json(ARTIFACT).unwrap();
+    let view     = View::new(Calldata::from_hex(SWAP), WithSig::True);
+    let out      = contract.decode_view(&view).unwrap();
+    assert_eq!(out.name, "swapExactTokensForETHSupportingFeeOnTransferTokens");
+    assert_eq!(out.get("deadline").unwrap().to_string(), "1696668933");
+    assert_eq!(out.get("path").unwrap().to_string(),
+      "[0xff970a61a04b1ca14834a43f5de4533ebddb5cc8, 0x82af49447d8a07e3bd95bd0d56f35241523fbab1]");
+    assert!(contract.decode_call(&hex_to_bytes("deadbeef")).is_err());
+  }
+
+  #[test]
+  fn decode_log_and_revert() {
+    let contract = Contract::from_json(ARTIFACT).unwrap();
+    let log = Log::from_hex(&[
+      "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+      "0x0000000000000000000000001111111111111111111111111111111111111111",
+      "0x0000000000000000000000002222222222222222222222222222222222222222"
+    ], "0x0000000000000000000000000000000000000000000000000000000000000001");
+    assert_eq!(contract.decode_log(&log).unwrap().get("value"), Some(&Value::Uint(kawala::kwl32::util::pad32l(&[1]))));
+
+    let sel  = kawala::kec256::hash::selector("Expired(uint256)");
+    let data = [sel.to_vec(), vec![0u8;31], vec![7]].concat();
+    assert_eq!(contract.decode_revert(&data).to_string(), "custom error Expired(deadline: 7)");
+    assert!(matches!(contract.decode_revert(&[1, 2, 3, 4]), Revert::Unknown { .. }));
+  }
+
+  #[test]
+  fn decode_return_and_constructor() {
+    let contract = Contract::from_json(ARTIFACT).unwrap();
+    let fill     = contract.abi().function("fill").unwrap().selector();
+    let out      = contract.decode_return(&fill, &[vec![0u8;31], vec![3]].concat()).unwrap();
+    assert_eq!(out.to_string(), "fill(filled: 3)");
+    let args     = [vec![0u8;12], vec![0x5c;20]].concat();
+    assert_eq!(contract.decode_constructor(&args).unwrap().to_string(),
+      "constructor(factory: 0x5c5c5c5c5c5c5c5c5c5c5c5c5c5c5c5c5c5c5c5c)");
+  }
+
+  #[test]
+  fn from_human() {
+    let contract = Contract::from_human(&[
+      "function swapExactTokensForETHSupportingFeeOnTransferTokens(uint256 amountIn, \
+       uint256 amountOutMin, address[] path, address to, uint256 deadline)"
+    ]).unwrap();
+    let out = contract.decode_call(&hex_to_bytes(&SWAP[2..])).unwrap();
+    assert_eq!(out.get("amountOutMin").unwrap().to_string(), "3028979035816185");
+  }
+}
@@ -0,0 +1,125 @@
+//------------------------------------------ DIFF ----------------------------------------------//
+// ----------------------------------------------------------------------------------------------//
+#[cfg(test)]
+mod diff {
+  use kawala::{ View, Calldata, WithSig, Contract };
+  use kawala::abi::{ Function, Value };
+  use kawala::diff::{ self, Change, WordOp };
+  use kawala::bai::con::hex_to_bytes;
+  use kawala::kwl32::util::pad32l;
+  use std::ops::Range;
+
+  const SWAP : &str = "function swapExactTokensForTokens(uint256 amountIn, uint256 amountOutMin, \
+                       address[] path, address to, uint256 deadline)";
+
+  // swap with the given min out and route
+  fn swap(min : &str, path : &[&str]) -> String {
+    let word = |h : &str| "0".repeat(64 - h.len()) + h;
+    let mut call = "0x38ed1739".to_owned()
+      + &word("3e8") + &word(min) + &word("a0") + &word(&"11".repeat(20)) + &word("65211d05")
+      + &word(&format!("{:x}", path.len()));
+    path . iter() . for_each(|p| call += &word(p));
+    call
+  }
+
+// --------------------------------------- SEMANTIC ---------------------------------------------//
+
+  #[test]
+  fn changed_and_added() {
+    let f = Function::parse(SWAP).unwrap();
+    let a = hex_to_bytes(&swap("4c3f88", &[&"aa".repeat(20), &"bb".repeat(20)])[2..]);
+    let b = hex_to_bytes(&swap("0",      &[&"aa".repeat(20), &"cc".repeat(20), &"bb".repeat(20)])[2..]);
+    let d = diff::diff_calls(&f, &a, &b).unwrap();
+    assert_eq!(d.changes.len(), 3);
+    assert_eq!(d.changes[0].to_string(), "amountOutMin: 4997000 -> 0");
+    assert_eq!(d.changes[1].path(), "path[1]");
+    assert_eq!(d.changes[2], Change::Added { path : "path[2]".to_owned(), value : Value::Address([0xbb;20]) });
+    assert_eq!(d.to_string(), format!(
+      "swapExactTokensForTokens:\n  amountOutMin: 4997000 -> 0\n  path[1]: 0x{} -> 0x{}\n  path[2] added: 0x{}\n",
+      "bb".repeat(20), "cc".repeat(20), "bb".repeat(20)
+    ));
+  }
+
+  #[test]
+  fn removed_and_same() {
+    let f = Function::parse(SWAP).unwrap();
+    let a = hex_to_bytes(&swap("1", &[&"aa".repeat(20), &"bb".repeat(20)])[2..]);
+    let b = hex_to_bytes(&swap("1", &[&"aa".repeat(20)])[2..]);
+    let d = diff::diff_calls(&f, &a, &b).unwrap();
+    assert_eq!(d.changes, [Change::Removed { path : "path[1]".to_owned(), value : Value::Address([0xbb;20]) }]);
+    assert!(diff::diff_calls(&f, &a, &a).unwrap().is_empty());
+  }
+
+  #[test]
+  fn views_and_contract() {
+    let mut a = View::new(Calldata::from_hex(&swap("1", &[&"aa".repeat(20)])), WithSig::True);
+    let     b = View::new(Calldata::from_hex(&swap("2", &[&"aa".repeat(20)])), WithSig::True);
+    assert!(diff::diff_views(&a, &b).is_err());
+    a.bind_sig(SWAP).unwrap();
+    let d = diff::diff_views(&a, &b).unwrap();
+    assert_eq!(d.changes, [Change::Changed {
+      path : "amountOutMin".to_owned(), from : Value::Uint(pad32l(&[1])), to : Value::Uint(pad32l(&[2]))
+    }]);
+    let c = Contract::from_human(&[SWAP]).unwrap();
+    assert_eq!(c.diff_calls(&a.bytes(), &b.bytes()).unwrap(), d);
+  }
+
+  #[test]
+  fn unnamed_tuple_paths() {
+    let f = Function::parse("f((uint256,address) pair)").unwrap();
+    let a = [vec![0u8;64]].concat();
+    let b = [vec![0u8;31], vec![5], vec![0u8;32]].concat();
+    let sel = f.selector().to_vec();
+    let d = diff::diff_calls(&f, &[sel.clone(), a].concat(), &[sel, b].concat()).unwrap();
+    assert_eq!(d.changes[0].to_string(), "pair[0]: 0 -> 5");
+  }
+
+// -------------------------------------- STRUCTURAL --------------------------------------------//
+
+  #[test]
+  fn words_aligned() {
+    // grown path, every word after the inserted address shifts by one
+    let a = View::new(Calldata::from_hex(&swap("1", &[&"aa".repeat(20), &"bb".repeat(20)])), WithSig::True);
+    let b = View::new(Calldata::from_hex(&swap("1", &[&"aa".repeat(20), &"cc".repeat(20), &"bb".repeat(20)])), WithSig::True);
+    let d = diff::diff_words(&a, &b);
+    assert!(!d.is_same());
+    assert_eq!(d.changes(), [
+      &WordOp::Changed { a : 5, b : 5, ranges : vec![Range { start : 31, end : 32 }] },
+      &WordOp::Inserted { b : 7 }
+    ]);
+    assert!(diff::diff_words(&a, &a).is_same());
+  }
+
+  #[test]
+  fn words_changed_bytes() {
+    let a = Calldata::from_hex(&("0".repeat(64) + "00ff" + &"0".repeat(56) + "abcd"));
+    let b = Calldata::from_hex(&("0".repeat(64) + "0000" + &"0".repeat(56) + "abce"));
+    let d = diff::diff_calldata(&a, &b);
+    assert_eq!(d.ops, [
+      WordOp::Same { a : 0, b : 0 },
+      WordOp::Changed { a : 1, b : 1, ranges : vec![1..2, 31..32] }
+    ]);
+  }
+
+  #[test]
+  fn words_removed() {
+    let a = Calldata::from_hex(&("0".repeat(63) + "1" + &"0".repeat(63) + "2" + &"0".repeat(63) + "3"));
+    let b = Calldata::from_hex(&("0".repeat(63) + "1" + &"0".repeat(63) + "3"));
+    let d = diff::diff_calldata(&a, &b);
+    assert_eq!(d.changes(), [&WordOp::Removed { a : 1 }]);
+  }
+
+  #[test]
+  fn words_render() {
+    let a = Calldata::from_hex(&("0".repeat(60) + "0102"));
+    let b = Calldata::from_hex(&("0".repeat(60) + "0103"));
+    let d = diff::diff_calldata(&a, &b);
+    let plain = d.render(false);
+    assert_eq!(plain, format!(
+      "sig \n~ 0 0  {}\n       {}\n       {}^^\n", "0".repeat(60) + "0102", "0".repeat(60) + "0103", " ".repeat(62)
+    ));
+    assert_eq!(d.to_string(), plain);
+    let colour = d.render(true);
+    assert!(colour.contains("\x1b[31m02\x1b[0m") && colour.contains("\x1b[32m03\x1b[0m"));
+  }
+}
@@ -0,0 +1,46 @@
+//--------                       -------- KEC256::HASH TEST --------                     --------//
+//-----------------------------------------------------------------------------------------------//
+#[cfg(test)]
+mod kec256_hash {
+  use kawala::kec256::hash;
+  use kawala::bai::con::bytes_to_hex;
+
+//--------                        --------   KECCAK256   --------                        --------//
+
+  // empty input
+  #[test]
+  fn keccak256_empty() {
+    assert_eq!(
+      bytes_to_hex(&hash::keccak256(&[])),
+      "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    );
+  }
+
+  // short input
+  #[test]
+  fn keccak256_short() {
+    assert_eq!(
+      bytes_to_hex(&hash::keccak256(b"Transfer(address,address,uint256)")),
+      "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+    );
+  }
+
+  // input spanning more than one block of the rate
+  #[test]
+  fn keccak256_multi_block() {
+    let input = [0x61u8;200];
+    let once  = hash::keccak256(&input);
+    assert_eq!(once, hash::keccak256(&input));
+    assert_ne!(once, hash::keccak256(&input[..199]));
+    // exact rate boundary pads into a fresh block
+    assert_ne!(hash::keccak256(&[0u8;136]), hash::keccak256(&[0u8;135]));
+  }
+
+//--------                        --------    SELECTOR   --------                        --------//
+
+  #[test]
+  fn selector_known() {
+    assert_eq!(hash::selector("transfer(address,uint256)"), [0xa9, 0x05, 0x9c, 0xbb]);
+    assert_eq!(hash::selector("approve(address,uint256)"),  [0x09, 0x5e, 0xa7, 0xb3]);
+  }
+}
@@ -277,6 +277,36 @@ mod kwl32_util {
     assert_eq!(util::or32(&a, &a), a);
   }
 
+//--------                        --------  NEG32 DEC32  --------                        --------//
+
+  #[test]
+  fn neg32_round_trip() {
+    let one = util::pad32l(&[1]);
+    assert_eq!(util::neg32(&one), [0xFFu8;32]);
+    assert_eq!(util::neg32(&util::neg32(&one)), one);
+    assert_eq!(util::neg32(&[0u8;32]), [0u8;32]);
+  }
+
+  #[test]
+  fn dec32_values() {
+    assert_eq!(util::dec32(&[0u8;32]), "0");
+    assert_eq!(util::dec32(&util::pad32l(&[0x27, 0x10])), "10000");
+    assert_eq!(
+      util::dec32(&[0xFFu8;32]),
+      "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+    );
+  }
+
+  #[test]
+  fn from_dec32_round_trip() {
+    let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+    assert_eq!(util::from_dec32(max), Some([0xFFu8;32]));
+    assert_eq!(util::from_dec32("10000"), Some(util::pad32l(&[0x27, 0x10])));
+    assert_eq!(util::from_dec32("115792089237316195423570985008687907853269984665640564039457584007913129639936"), None);
+    assert_eq!(util::from_dec32("12a"), None);
+    assert_eq!(util::from_dec32(""),    None);
+  }
+
 }
 
 //-----------------------------------------------------------------------------------------------//
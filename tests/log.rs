@@ -0,0 +1,79 @@
+//------------------------------------------- LOG ----------------------------------------------//
+// ----------------------------------------------------------------------------------------------//
+#[cfg(test)]
+mod log {
+  use kawala::{ Log, Word };
+  use kawala::log::topic0;
+  use kawala::abi::{ Event, Value, AbiError };
+  use kawala::bai::con::bytes_to_hex;
+  use kawala::kec256::hash::keccak256;
+
+  const TRANSFER : &str = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+  const FROM     : &str = "0x0000000000000000000000001111111111111111111111111111111111111111";
+  const TO       : &str = "0x0000000000000000000000002222222222222222222222222222222222222222";
+  const VALUE    : &str = "0x0000000000000000000000000000000000000000000000000000000000002710";
+
+// ----------------------------------------- TOPIC0 ---------------------------------------------//
+
+  #[test]
+  fn topic0_from_signature() {
+    let named = topic0("Transfer(address indexed from, address indexed to, uint256 value)").unwrap();
+    assert_eq!(named, Word::from_hex(TRANSFER));
+    assert_eq!(topic0("Transfer(address,address,uint256)").unwrap(), named);
+  }
+
+// ----------------------------------------- DECODE ---------------------------------------------//
+
+  #[test]
+  fn decode_transfer() {
+    let log = Log::from_hex(&[TRANSFER, FROM, TO], VALUE);
+    let out = log.decode_sig("Transfer(address indexed from, address indexed to, uint256 value)").unwrap();
+    assert_eq!(out.to_string(),
+      "Transfer(from: 0x1111111111111111111111111111111111111111, \
+       to: 0x2222222222222222222222222222222222222222, value: 10000)");
+    assert_eq!(log.data().word_count(), 1);
+  }
+
+  #[test]
+  fn decode_wrong_event() {
+    let log = Log::from_hex(&[TRANSFER, FROM, TO], VALUE);
+    let err = log.decode_sig("Approval(address indexed, address indexed, uint256)");
+    assert!(matches!(err, Err(AbiError::Mismatch(_))));
+    // right event, wrong indexing
+    assert!(log.decode_sig("Transfer(address indexed, address, uint256)").is_err());
+  }
+
+  #[test]
+  fn decode_anonymous() {
+    let event = Event::parse("Ping(address indexed who, uint256 n) anonymous").unwrap();
+    let log   = Log::from_hex(&[FROM], VALUE);
+    assert!(log.matches(&event));
+    assert_eq!(log.decode(&event).unwrap().get("n"), Some(&Value::Uint(Word::from_hex(VALUE).as_bytes32())));
+  }
+
+  #[test]
+  fn decode_indexed_dynamic_is_hashed() {
+    let event = Event::parse("Registered(string indexed name, address owner, string note)").unwrap();
+    let name  = "0x".to_owned() + &bytes_to_hex(&keccak256(b"alice"));
+    let data  = [
+      &FROM[2..],
+      "0000000000000000000000000000000000000000000000000000000000000040",
+      "0000000000000000000000000000000000000000000000000000000000000003",
+      "6869210000000000000000000000000000000000000000000000000000000000"
+    ].concat();
+    let topic = "0x".to_owned() + &bytes_to_hex(&event.topic0());
+    let log   = Log::from_hex(&[&topic, &name], &data);
+    let out   = log.decode(&event).unwrap();
+    assert_eq!(out.get("name"), Some(&Value::Hashed(keccak256(b"alice"))));
+    assert_eq!(out.get("note"), Some(&Value::String("hi!".to_owned())));
+    assert_eq!(log.data().word_count(), 4);
+  }
+
+  #[test]
+  fn empty_data() {
+    let sync = topic0("Sync()").unwrap().hex_0x();
+    let log  = Log::from_hex(&[&sync], "0x");
+    assert_eq!(log.data().word_count(), 0);
+    assert_eq!(log.decode_sig("Sync()").unwrap().fields.len(), 0);
+  }
+}
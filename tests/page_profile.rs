@@ -0,0 +1,49 @@
+//-------------------------------------- PAGE PROFILE ------------------------------------------//
+// ----------------------------------------------------------------------------------------------//
+// the numbers behind the page staying a `Vec`, see the note after `__splice`.
+//   cargo test --release --test page_profile -- --ignored --nocapture
+#[cfg(test)]
+mod page_profile {
+  use kawala::{ View, Calldata, WithSig, Word };
+  use std::collections::VecDeque;
+  use std::hint::black_box;
+  use std::time::Instant;
+
+  const ROUNDS : u32 = 200_000;
+
+  // mean ns a round of `f` takes, after a warm up
+  fn time<F: FnMut()>(mut f : F) -> f64 {
+    (0..ROUNDS / 10) . for_each(|_| f());
+    let start = Instant::now();
+    (0..ROUNDS) . for_each(|_| f());
+    start.elapsed().as_nanos() as f64 / ROUNDS as f64
+  }
+
+// --------------------------------------- FRONT OPS --------------------------------------------//
+
+  // take a word off the front and put it on the back, the page stays the same
+  // size. `slice` keeps the deque contiguous after each round, which it has
+  // to be to hand out a `&[Word]` from `&self` the way `__page` does
+  #[test]
+  #[ignore]
+  fn front_ops() {
+    println!("{:>6} {:>10} {:>10} {:>10} {:>10}", "words", "vec", "deque", "slice", "view");
+    for n in [8usize, 64, 512, 4096] {
+      let words = (0..n) . map(|i| Word::from_bytes(&[i as u8; 32])) . collect::<Vec<Word>>();
+      let mut vec = words.clone();
+      let vec_ns = time(|| { let w = vec.remove(0); vec.push(black_box(w)) });
+      let mut deque = VecDeque::from(words.clone());
+      let deque_ns = time(|| { let w = deque.pop_front().unwrap(); deque.push_back(black_box(w)) });
+      let mut slice = VecDeque::from(words.clone());
+      let slice_ns = time(|| {
+        let w = slice.pop_front().unwrap(); slice.push_back(black_box(w));
+        black_box(slice.make_contiguous().len());
+      });
+      let bytes = words . iter() . flat_map(|w| w.bytes().to_vec()) . collect::<Vec<u8>>();
+      let mut view = View::new(Calldata::from_bytes(&bytes), WithSig::False);
+      let view_ns = time(|| { let w = view.__pop_front(); view.__append(black_box(w)) });
+      println!("{:>6} {:>8.1}ns {:>8.1}ns {:>8.1}ns {:>8.1}ns", n, vec_ns, deque_ns, slice_ns, view_ns);
+      assert_eq!(view.word_count(), n);
+    }
+  }
+}
@@ -0,0 +1,132 @@
+//---------------------------------------- REGISTRY --------------------------------------------//
+// ----------------------------------------------------------------------------------------------//
+#[cfg(test)]
+mod registry {
+  use kawala::{ Registry, View, Calldata, WithSig };
+  use kawala::abi::Abi;
+
+// ---------------------------------------- BUNDLED ---------------------------------------------//
+
+  #[test]
+  fn bundled_lookup() {
+    let reg = Registry::bundled();
+    assert_eq!(reg.lookup_hex("0xa9059cbb"), ["transfer(address,uint256)"]);
+    assert_eq!(reg.lookup(&[0x09, 0x5e, 0xa7, 0xb3]), ["approve(address,uint256)"]);
+    assert!(reg.lookup_hex("deadbeef").is_empty());
+  }
+
+  #[test]
+  #[cfg(feature = "std")]
+  fn view_sig_candidates() {
+    let call = "0x791ac947".to_owned() + &"0".repeat(64);
+    let view = View::new(Calldata::from_hex(&call), WithSig::True);
+    assert_eq!(view.sig_candidates(), [
+      "swapExactTokensForETHSupportingFeeOnTransferTokens(uint256,uint256,address[],address,uint256)"
+    ]);
+    let view = View::new(Calldata::from_hex(&call), WithSig::False);
+    assert!(view.sig_candidates().is_empty());
+  }
+
+  // burn(uint256) and collate_propagate_storage(bytes16) share 0x42966c68
+  #[test]
+  fn collisions_kept() {
+    let mut reg = Registry::bundled();
+    reg.insert("collate_propagate_storage(bytes16)");
+    let view = View::new(Calldata::from_hex("42966c68"), WithSig::True);
+    assert_eq!(view.sig_candidates_in(&reg), [
+      "burn(uint256)", "collate_propagate_storage(bytes16)"
+    ]);
+  }
+
+// ---------------------------------------- IMPORT ----------------------------------------------//
+
+  #[test]
+  fn import_text_dump() {
+    let dump = "# comment\n\
+                0xa9059cbb transfer(address,uint256)\n\
+                42, balanceOf(address), 0x70a08231\n\
+                totalSupply()\n\
+                0xdeadbeef transfer(address,uint256)\n\
+                not a signature\n";
+    let mut reg = Registry::new();
+    assert_eq!(reg.import_text(dump), 3);
+    assert_eq!(reg.len(), 3);
+    assert_eq!(reg.lookup_hex("18160ddd"), ["totalSupply()"]);
+  }
+
+  #[test]
+  fn import_abi_json() {
+    let abi = r#"{ "abi": [
+      { "type": "function", "name": "exactInputSingle", "inputs": [
+        { "name": "params", "type": "tuple", "components": [
+          { "name": "tokenIn",  "type": "address" }, { "name": "tokenOut", "type": "address" },
+          { "name": "fee",      "type": "uint24"  }, { "name": "recipient", "type": "address" },
+          { "name": "amountIn", "type": "uint256" }, { "name": "amountOutMinimum", "type": "uint256" },
+          { "name": "sqrtPriceLimitX96", "type": "uint160" } ] } ] },
+      { "type": "event", "name": "Transfer", "inputs": [] },
+      { "type": "error", "name": "Unauthorized", "inputs": [] }
+    ] }"#;
+    let mut reg = Registry::new();
+    assert_eq!(reg.import_abi_json(abi), 2);
+    assert_eq!(reg.lookup_hex("04e45aaf"), [
+      "exactInputSingle((address,address,uint24,address,uint256,uint256,uint160))"
+    ]);
+    assert_eq!(reg.import_abi_json("[ garbage"), 0);
+    // a second import adds nothing new
+    assert_eq!(reg.import_abi_json(abi), 0);
+    assert_eq!(reg.len(), 2);
+  }
+
+  // names, spacing and `uint` aliases hash as the canonical signature
+  #[test]
+  fn insert_canonical() {
+    let mut reg = Registry::new();
+    assert_eq!(reg.insert("transfer(address to, uint256 amount)"), [0xa9, 0x05, 0x9c, 0xbb]);
+    assert_eq!(reg.insert("transfer(address,uint)"), [0xa9, 0x05, 0x9c, 0xbb]);
+    assert_eq!(reg.insert("function transfer(address, uint256) external returns (bool)"), [0xa9, 0x05, 0x9c, 0xbb]);
+    assert_eq!(reg.lookup_hex("a9059cbb"), ["transfer(address,uint256)"]);
+    assert_eq!(reg.len(), 1);
+    // what doesn't parse still goes in, without its whitespace
+    reg.insert("odd(thing, stuff)");
+    assert_eq!(reg.lookup(&kawala::kec256::hash::selector("odd(thing,stuff)")), ["odd(thing,stuff)"]);
+  }
+
+  #[test]
+  fn import_abi_json_skips_bad_entries() {
+    // an unknown type and an unreadable param don't cost the good entries
+    let abi = r#"[
+      { "type": "function", "name": "approve", "inputs": [
+        { "name": "spender", "type": "address" }, { "name": "amount", "type": "uint256" } ] },
+      { "type": "mystery", "name": "what" },
+      { "type": "function", "name": "broken", "inputs": [ { "name": "x", "type": "uint7" } ] },
+      { "type": "error", "name": "Unauthorized", "inputs": [] }
+    ]"#;
+    let mut reg = Registry::new();
+    assert_eq!(reg.import_abi_json(abi), 2);
+    assert_eq!(reg.lookup_hex("095ea7b3"), ["approve(address,uint256)"]);
+    assert!(Abi::from_json(abi).is_err());
+    let (lossy, skipped) = Abi::from_json_lossy(abi).unwrap();
+    assert_eq!((lossy.functions.len(), lossy.errors.len(), skipped), (1, 1, 2));
+  }
+
+// -------------------------------------- PERSISTENCE -------------------------------------------//
+
+  #[test]
+  fn text_round_trip() {
+    let reg = Registry::bundled();
+    assert_eq!(Registry::from_text(&reg.to_text()), reg);
+  }
+
+  #[test]
+  #[cfg(feature = "std")]
+  fn save_and_load() {
+    let path = std::env::temp_dir().join(format!("kawala_registry_{}.txt", std::process::id()));
+    let mut reg = Registry::new();
+    reg.insert("transfer(address, uint256)");
+    reg.save(&path).unwrap();
+    let loaded = Registry::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, reg);
+    assert_eq!(loaded.lookup_hex("a9059cbb"), ["transfer(address,uint256)"]);
+  }
+}
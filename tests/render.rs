@@ -0,0 +1,132 @@
+//------------------------------------------ RENDER --------------------------------------------//
+// ----------------------------------------------------------------------------------------------//
+#[cfg(test)]
+mod render {
+  use kawala::{ View, Calldata, WithSig, Render, Hexdump };
+  use kawala::render::parse_hexdump;
+  use kawala::error::KawalaError;
+
+  // swap(uint256 amountIn, uint256 amountOutMin, address[] path), zero min out
+  fn swap() -> View {
+    let word = |h : &str| "0".repeat(64 - h.len()) + h;
+    let call = "0x12345678".to_owned()
+      + &word("3e8") + &word("0") + &word("60") + &word("1") + &word(&"aa".repeat(20));
+    View::new(Calldata::from_hex(&call), WithSig::True)
+  }
+
+// ---------------------------------------- DEFAULTS --------------------------------------------//
+
+  #[test]
+  fn default_columns() {
+    let view = swap();
+    assert_eq!(Render::new().render(&view), format!(
+      "sig 12345678\n0  0x00  {}\n1  0x20  {}\n2  0x40  {}\n3  0x60  {}\n4  0x80  {}\n",
+      view.word(0), view.word(1), view.word(2), view.word(3), view.word(4)
+    ));
+    assert_eq!(view.to_string(), Render::new().render(&view));
+  }
+
+  #[test]
+  fn slot_names_and_notes() {
+    let mut view = swap();
+    view.bind_sig("swap(uint256 amountIn, uint256 amountOutMin, address[] path)").unwrap();
+    let out = Render::new().annotate(4, "weth").offset(false).render(&view);
+    assert_eq!(out, format!(
+      "sig 12345678\n0  {}  // amountIn\n1  {}  // amountOutMin\n2  {}  // path\n3  {}\n4  {}  // weth\n",
+      view.word(0), view.word(1), view.word(2), view.word(3), view.word(4)
+    ));
+    assert!(!Render::new().slot_names(false).render(&view).contains("//"));
+  }
+
+// -------------------------------------- ZERO RUNS ---------------------------------------------//
+
+  #[test]
+  fn zero_runs_folded() {
+    let view = View::new(Calldata::from_bytes(&[[1u8;32], [0u8;32], [0u8;32], [0u8;32], [2u8;32]].concat()), WithSig::False);
+    assert_eq!(Render::new().index(false).render(&view), format!(
+      "0x00  {}\n0x20  3 zero words\n0x80  {}\n", "01".repeat(32), "02".repeat(32)
+    ));
+    assert_eq!(Render::new().render(&view).lines().nth(1), Some("1..3  0x20  3 zero words"));
+    assert_eq!(Render::new().zero_runs(4).render(&view).lines().count(), 5);
+    assert_eq!(Render::new().zero_runs(0).render(&view).lines().count(), 5);
+    // a note keeps its word out of the run
+    assert_eq!(Render::new().annotate(2, "here").render(&view).lines().count(), 5);
+  }
+
+// ---------------------------------- PREFIX, WIDTHS, IO ----------------------------------------//
+
+  #[test]
+  fn prefix_widths_and_io() {
+    let view = View::new(Calldata::from_hex("0xa9059cbb0102"), WithSig::True);
+    let render = Render::new().prefix(true).widths(3, 4);
+    assert_eq!(render.render(&view), "sig 0xa9059cbb\n  0  0x0000  0x0102\n");
+    #[cfg(feature = "std")] {
+      let mut out = Vec::new();
+      render.render_io(&view, &mut out).unwrap();
+      assert_eq!(String::from_utf8(out).unwrap(), render.render(&view));
+    }
+    assert_eq!(Render::default().render(&View::new(Calldata::from_hex(""), WithSig::False)), "");
+  }
+
+// --------------------------------------- HEXDUMP ----------------------------------------------//
+
+  // transfer sig, a padded address and a packed string tail
+  fn packed() -> View {
+    View::new(Calldata::from_hex(&("a9059cbb".to_owned() + &"00".repeat(12) + &"aa".repeat(20) + "48656c6c6f")), WithSig::True)
+  }
+
+  #[test]
+  fn hexdump_rows_and_marks() {
+    assert_eq!(packed().hexdump(), [
+      "00000000  a9 05 9c bb:00 00 00 00 00 00 00 00 00 00 00 00  |................|",
+      "00000010  aa aa aa aa aa aa aa aa aa aa aa aa aa aa aa aa  |................|",
+      "00000020  aa aa aa aa:48 65 6c 6c 6f                       |....Hello|",
+      "00000029", ""
+    ].join("\n"));
+    let call = Calldata::from_hex("48692100");
+    assert_eq!(call.hexdump(), "00000000  48 69 21 00                                      |Hi!.|\n00000004\n");
+    assert_eq!(Calldata::from_hex("").hexdump(), "00000000\n");
+    // raw dumps mark every 32 bytes
+    let marked = Hexdump::new().width(32).word_marks(true).dump(&[1u8;40]);
+    assert_eq!(marked.lines().nth(1).unwrap(), "00000020 :01 01 01 01 01 01 01 01".to_owned() + &"   ".repeat(24) + "  |........|");
+  }
+
+  #[test]
+  fn hexdump_highlights() {
+    let plain = Hexdump::new().width(8).highlight(4..6).highlight(7..9).dump_view(&packed());
+    assert_eq!(plain.lines().take(4).collect::<Vec<_>>(), [
+      "00000000  a9 05 9c bb 00 00 00 00  |........|",
+      "                      ^^ ^^    ^^",
+      "00000008  00 00 00 00 00 00 00 00  |........|",
+      "          ^^"
+    ]);
+    let colour = Hexdump::new().width(8).highlight(36..37).colour(true).dump_view(&packed());
+    assert!(colour.contains(" \x1b[7m48\x1b[0m") && colour.contains("|....\x1b[7mH\x1b[0mell|"));
+    assert!(!colour.contains("^^"));
+  }
+
+  #[test]
+  fn hexdump_round_trip() {
+    let view = packed();
+    for dump in [
+      view.hexdump(),
+      Hexdump::new().width(5).highlight(0..40).dump_view(&view),
+      Hexdump::new().width(7).highlight(3..9).colour(true).word_marks(true).dump_view(&view)
+    ] {
+      assert_eq!(Calldata::from_hexdump(&dump).unwrap().bytes(), view.bytes());
+    }
+    assert_eq!(parse_hexdump(""), Ok(vec![]));
+  }
+
+  #[test]
+  fn hexdump_edited() {
+    // a byte changed, one added to a row and the gutter left stale
+    let dump = Calldata::from_hex("48692100").hexdump()
+      . replace("48 69 21 00", "48 65 6c 6c 6f");
+    assert_eq!(Calldata::from_hexdump(&dump).unwrap().bytes(), b"Hello");
+    assert_eq!(parse_hexdump("00000000  48 6"), Err(KawalaError::Hex("6".to_owned())));
+    assert_eq!(parse_hexdump("00000000  48 zz"), Err(KawalaError::Hex("zz".to_owned())));
+    assert_eq!(parse_hexdump("00000000  +f"), Err(KawalaError::Hex("+f".to_owned())));
+    assert_eq!(parse_hexdump("offset  48"), Err(KawalaError::Hex("offset".to_owned())));
+  }
+}
@@ -0,0 +1,84 @@
+//----------------------------------------- REVERT ---------------------------------------------//
+// ----------------------------------------------------------------------------------------------//
+#[cfg(all(test, feature = "std"))]
+mod revert {
+  use kawala::{ Registry, View, Calldata, WithSig };
+  use kawala::revert::{ self, Revert };
+  use kawala::bai::con::hex_to_bytes;
+
+  // Error("Too little received")
+  const ERROR_STRING : &str = concat!(
+    "08c379a0",
+    "0000000000000000000000000000000000000000000000000000000000000020",
+    "0000000000000000000000000000000000000000000000000000000000000013",
+    "546f6f206c6974746c6520726563656976656400000000000000000000000000"
+  );
+
+// ---------------------------------------- BUILT IN --------------------------------------------//
+
+  #[test]
+  fn error_string() {
+    let data = hex_to_bytes(ERROR_STRING);
+    assert_eq!(Revert::decode(&data), Revert::Error("Too little received".to_owned()));
+    assert_eq!(revert::explain(&data), "reverted: Too little received");
+  }
+
+  #[test]
+  fn error_string_from_view() {
+    let view = View::new(Calldata::from_hex(ERROR_STRING), WithSig::True);
+    assert_eq!(Revert::from_view(&view), Revert::Error("Too little received".to_owned()));
+  }
+
+  #[test]
+  fn panic_codes() {
+    let data = hex_to_bytes(&("4e487b71".to_owned() + &"0".repeat(62) + "11"));
+    let rev  = Revert::decode(&data);
+    assert_eq!(rev.panic_code(), Some(0x11));
+    assert_eq!(rev.to_string(), "panic 0x11: arithmetic overflow or underflow");
+    assert_eq!(revert::panic_reason(0x32), "array index out of bounds");
+  }
+
+  #[test]
+  fn empty() {
+    assert_eq!(Revert::decode(&[]), Revert::Empty);
+  }
+
+// ----------------------------------------- CUSTOM ---------------------------------------------//
+
+  #[test]
+  fn custom_with_signature() {
+    let sig  = "InsufficientBalance(uint256 available, uint256 required)";
+    let sel  = kawala::kec256::hash::selector("InsufficientBalance(uint256,uint256)");
+    let data = [sel.to_vec(), hex_to_bytes(&("0".repeat(63) + "1" + &"0".repeat(63) + "2"))].concat();
+    let rev  = Revert::decode_with(&data, sig).unwrap();
+    assert_eq!(rev.to_string(), "custom error InsufficientBalance(available: 1, required: 2)");
+    assert!(Revert::decode_with(&data, "Other(uint256)").is_err());
+  }
+
+  #[test]
+  fn custom_through_registry() {
+    let mut reg = Registry::new();
+    let sel  = reg.insert("Unauthorized(address)");
+    let data = [sel.to_vec(), vec![0u8;12], vec![0xaa;20]].concat();
+    assert_eq!(Revert::decode_in(&data, &reg).to_string(),
+      "custom error Unauthorized(0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa)");
+    // unknown to the bundled set
+    assert!(matches!(Revert::decode(&data), Revert::Unknown { candidates, .. } if candidates.is_empty()));
+  }
+
+// ----------------------------------------- RETURN ---------------------------------------------//
+
+  #[test]
+  fn return_data() {
+    // getAmountsOut => uint256[] [1000, 997]
+    let data = hex_to_bytes(concat!(
+      "0000000000000000000000000000000000000000000000000000000000000020",
+      "0000000000000000000000000000000000000000000000000000000000000002",
+      "00000000000000000000000000000000000000000000000000000000000003e8",
+      "00000000000000000000000000000000000000000000000000000000000003e5"
+    ));
+    let out = revert::decode_return(&data, "(uint256[] amounts)").unwrap();
+    assert_eq!(out.to_string(), "(amounts: [1000, 997])");
+    assert_eq!(revert::decode_return(&data[32..64], "uint256").unwrap().to_string(), "(2)");
+  }
+}
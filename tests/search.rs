@@ -0,0 +1,117 @@
+//------------------------------------------ SEARCH --------------------------------------------//
+// ----------------------------------------------------------------------------------------------//
+#[cfg(test)]
+mod search {
+  use kawala::{ View, Calldata, WithSig, Found, KawalaError };
+  use kawala::abi::{ self, ParamType, Value };
+  use kawala::search::Pad;
+  use kawala::bai::con::hex_to_bytes;
+  use kawala::kec256::hash::selector;
+
+  const USDC : &str = "2791bca1f2de4661ed88a30c99a7a9449aa84174";
+  const WETH : &str = "7ceb23fd6bc0add59e62ac25578270cff1b9f619";
+
+  fn addr(hex : &str) -> Vec<u8> { hex_to_bytes(hex) }
+
+  fn transfer(to : &str, amount : u128) -> Vec<u8> {
+    Calldata::builder() . selector("transfer(address,uint256)") . address(to) . uint(amount) . build() . bytes() . to_vec()
+  }
+  // multicall(uint256,bytes[]) around the given calls
+  fn multicall(calls : &[Vec<u8>]) -> Vec<u8> {
+    let kinds  = [ParamType::Uint(256), ParamType::Array(Box::new(ParamType::Bytes))];
+    let values = [Value::Uint([0x11; 32]), Value::Array(calls . iter() . map(|c| Value::Bytes(c.clone())) . collect())];
+    [selector("multicall(uint256,bytes[])").to_vec(), abi::encode(&kinds, &values).unwrap()].concat()
+  }
+
+// ----------------------------------------- ALIGNED --------------------------------------------//
+
+  #[test]
+  fn aligned_words() {
+    let view = View::new(Calldata::from_bytes(&transfer(USDC, 1000)), WithSig::True);
+    assert_eq!(view.find_value(&addr(USDC)), [Found::Word { index : 0, offset : 0, pad : Pad::Left }]);
+    // the amount as a number, a whole word and not also loose bytes
+    assert_eq!(view.find_value(&[0x03, 0xe8]), [Found::Word { index : 1, offset : 32, pad : Pad::Left }]);
+    // fixed bytes sit on the left
+    let view = View::new(Calldata::from_hex(&("a9059cbb".to_owned() + "deadbeef" + &"0".repeat(56))), WithSig::True);
+    assert_eq!(view.find_value(&[0xde, 0xad, 0xbe, 0xef]), [Found::Word { index : 0, offset : 0, pad : Pad::Right }]);
+    assert!(view.find_value(&[]).is_empty());
+    assert!(view.find_value(&addr(WETH)).is_empty());
+  }
+
+  #[test]
+  fn replace_aligned() {
+    let mut view = View::new(Calldata::from_bytes(&transfer(USDC, 1000)), WithSig::True);
+    assert_eq!(view.replace_all(&addr(USDC), &addr(WETH)), Ok(1));
+    // a wider amount still goes in padded, the word stays a word
+    assert_eq!(view.replace_all(&[0x03, 0xe8], &[0x0f, 0x42, 0x40]), Ok(1));
+    assert_eq!(view.bytes(), transfer(WETH, 1_000_000));
+    assert_eq!(view.replace_all(&[0x0f, 0x42, 0x40], &[1; 33]), Err(KawalaError::TooLong { max : 32, found : 33 }));
+  }
+
+// ---------------------------------------- UNALIGNED -------------------------------------------//
+
+  #[test]
+  fn unaligned_bytes() {
+    let call = Calldata::builder() . byte(0x01) . address_packed(USDC) . uint_packed(1000, 3) . build();
+    let mut view = View::new(call, WithSig::False);
+    assert_eq!(view.find_value(&addr(USDC)), [Found::Bytes { offset : 1 }]);
+    assert_eq!(view.find_value(&[0x03, 0xe8]), [Found::Bytes { offset : 22 }]);
+    // packed keeps its width, longer doesn't fit and nothing is written
+    assert_eq!(view.replace_all(&[0x03, 0xe8], &[0x01, 0x00, 0x00]), Err(KawalaError::TooLong { max : 2, found : 3 }));
+    assert_eq!(view.replace_all(&[0x03, 0xe8], &[0x07]), Ok(1));
+    assert_eq!(view.replace_all(&addr(USDC), &addr(WETH)), Ok(1));
+    let expect = Calldata::builder() . byte(0x01) . address_packed(WETH) . uint_packed(7, 3) . build();
+    assert_eq!(view.bytes(), expect.bytes());
+  }
+
+  #[test]
+  fn mixed_and_in_order() {
+    // a word match and a packed copy behind it
+    let call = Calldata::builder() . address(USDC) . byte(0xff) . address_packed(USDC) . build();
+    let view = View::new(call, WithSig::False);
+    let found = view.find_value(&addr(USDC));
+    assert_eq!(found, [Found::Word { index : 0, offset : 0, pad : Pad::Left }, Found::Bytes { offset : 33 }]);
+    assert_eq!(found . iter() . map(|f| f.depth()) . collect::<Vec<_>>(), [0, 0]);
+  }
+
+// ----------------------------------------- NESTED ---------------------------------------------//
+
+  #[test]
+  fn nested_calls() {
+    let data = multicall(&[transfer(USDC, 5), transfer(WETH, 6), transfer(USDC, 7)]);
+    let view = View::new(Calldata::from_bytes(&data), WithSig::True);
+    let found = view.find_value(&addr(USDC));
+    assert_eq!(found.len(), 2);
+    for f in &found {
+      assert_eq!(f.depth(), 1);
+      // the inner call's first word, after its selector
+      assert_eq!(f.leaf(), &Found::Word { index : 0, offset : 4, pad : Pad::Left });
+      assert_eq!(view.read_bytes(f.offset() + 12, 20), addr(USDC));
+      assert!(matches!(f, Found::Nested { payload, .. } if view.read_bytes(*payload, 4) == selector("transfer(address,uint256)")));
+    }
+    // the same with the types to go on
+    let mut bound = view.clone();
+    bound.bind_sig("multicall(uint256 deadline, bytes[] data)").unwrap();
+    assert_eq!(bound.find_value(&addr(USDC)), found);
+  }
+
+  #[test]
+  fn replace_nested() {
+    let data = multicall(&[transfer(USDC, 5), transfer(WETH, 6), transfer(USDC, 7)]);
+    let mut view = View::new(Calldata::from_bytes(&data), WithSig::True);
+    assert_eq!(view.replace_all(&addr(USDC), &addr(WETH)), Ok(2));
+    assert_eq!(view.bytes(), multicall(&[transfer(WETH, 5), transfer(WETH, 6), transfer(WETH, 7)]));
+    assert!(view.find_value(&addr(USDC)).is_empty());
+  }
+
+  #[test]
+  fn nested_twice() {
+    let inner = multicall(&[transfer(USDC, 5)]);
+    let data  = multicall(&[transfer(WETH, 1), inner]);
+    let view  = View::new(Calldata::from_bytes(&data), WithSig::True);
+    let found = view.find_value(&addr(USDC));
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].depth(), 2);
+    assert_eq!(view.read_bytes(found[0].offset() + 12, 20), addr(USDC));
+  }
+}
@@ -0,0 +1,96 @@
+//------------------------------------------ SERIAL --------------------------------------------//
+// ----------------------------------------------------------------------------------------------//
+#[cfg(all(test, feature = "serde"))]
+mod serial {
+  use kawala::{ Bytes, Calldata, Signature, View, ViewConfig, Word, WithSig };
+  use kawala::abi::{ self, Function, Value };
+  use serde_test::{ assert_tokens, assert_ser_tokens, assert_de_tokens, assert_de_tokens_error, Configure, Token };
+
+  const TRANSFER : &str = "0xa9059cbb000000000000000000000000aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\
+                           00000000000000000000000000000000000000000000000000000000000003e8";
+
+// --------------------------------------- READABLE ---------------------------------------------//
+
+  #[test]
+  fn hex_strings() {
+    assert_tokens(&Word::from_hex("0102").readable(), &[Token::Str("0x0102")]);
+    assert_tokens(&Signature::from_hex("a9059cbb").readable(), &[Token::Str("0xa9059cbb")]);
+    assert_tokens(&Calldata::from_hex("ff").readable(), &[Token::Str("0xff")]);
+    assert_tokens(&Bytes::Bytes4([1, 2, 3, 4]).readable(), &[Token::Str("0x01020304")]);
+    let view = View::new(Calldata::from_hex(TRANSFER), WithSig::True);
+    let json = format!(r#"{{"sig":"0xa9059cbb","data":"0x{}"}}"#, &TRANSFER[10..]);
+    assert_eq!(serde_json::to_string(&view).unwrap(), json);
+    assert_eq!(serde_json::from_str::<View>(&json).unwrap(), view);
+  }
+
+  // no sig, or a longer header, comes back the same
+  #[test]
+  fn view_headers() {
+    for view in [
+      View::new(Calldata::from_hex("0x0102"), WithSig::False),
+      View::new(Calldata::from_hex(TRANSFER), WithSig::False),
+      View::with_config(Calldata::from_hex(TRANSFER), ViewConfig::new().header(8))
+    ] {
+      let back = serde_json::from_str::<View>(&serde_json::to_string(&view).unwrap()).unwrap();
+      assert_eq!((back.sig_bytes(), back.data_bytes()), (view.sig_bytes(), view.data_bytes()));
+      assert_eq!(back, view);
+    }
+    let view = View::new(Calldata::from_hex("0x0102"), WithSig::False);
+    assert_eq!(serde_json::to_string(&view).unwrap(), r#"{"sig":null,"data":"0x0102"}"#);
+    assert_eq!(serde_json::from_str::<View>(r#"{"data":"0x0102"}"#).unwrap(), view);
+  }
+
+  #[test]
+  fn readable_errors() {
+    assert_de_tokens_error::<serde_test::Readable<Word>>(&[Token::Str("0x0g")], "not hex `0x0g`");
+    assert!(serde_json::from_str::<Word>(&format!("\"{}\"", "00".repeat(33))).is_err());
+    assert!(serde_json::from_str::<Signature>("\"0x0102\"").is_err());
+    assert!(serde_json::from_str::<View>(r#"{"sig":"0x01"}"#).is_err());
+    // prefix is optional on the way in
+    assert_eq!(serde_json::from_str::<Calldata>("\"abcd\"").unwrap(), Calldata::from_hex("abcd"));
+  }
+
+// ---------------------------------------- BINARY ----------------------------------------------//
+
+  #[test]
+  fn raw_bytes() {
+    assert_tokens(&Word::from_hex("0102").compact(), &[Token::Bytes(&[1, 2])]);
+    assert_tokens(&Signature::from_hex("a9059cbb").compact(), &[Token::Bytes(&[0xa9, 0x05, 0x9c, 0xbb])]);
+    assert_tokens(&Calldata::from_hex("ff").compact(), &[Token::Bytes(&[0xff])]);
+    let view = View::new(Calldata::from_hex("a9059cbb01"), WithSig::True);
+    assert_tokens(&view.compact(), &[
+      Token::Struct { name : "View", len : 2 },
+      Token::Str("sig"),  Token::Some, Token::Bytes(&[0xa9, 0x05, 0x9c, 0xbb]),
+      Token::Str("data"), Token::Bytes(&[0x01]),
+      Token::StructEnd
+    ]);
+    // the fixed variants come back for 4 and 32 bytes
+    assert_de_tokens(&Bytes::Bytes4([1, 2, 3, 4]).compact(), &[Token::Bytes(&[1, 2, 3, 4])]);
+    let word = serde_json::from_str::<Bytes>(&format!("\"0x{}\"", "00".repeat(32))).unwrap();
+    assert!(matches!(word, Bytes::Bytes32(_)));
+    assert!(matches!(serde_json::from_str::<Bytes>("\"0x0102\"").unwrap(), Bytes::Array(_)));
+    // formats without a bytes type can send a list
+    assert_de_tokens(&Word::from_hex("0102").compact(), &[Token::Seq { len : Some(2) }, Token::U8(1), Token::U8(2), Token::SeqEnd]);
+  }
+
+// ----------------------------------------- VALUES ---------------------------------------------//
+
+  #[test]
+  fn value_tree() {
+    let f = Function::parse("transfer(address to, uint256 amount)").unwrap();
+    let view = View::new(Calldata::from_hex(TRANSFER), WithSig::True);
+    let decoded = f.decode_input(&view.bytes()).unwrap();
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), serde_json::json!({
+      "name" : "transfer",
+      "fields" : { "to" : format!("0x{}", "aa".repeat(20)), "amount" : "1000" }
+    }));
+    let f = Function::parse("f((bool,string[]),int8)").unwrap();
+    let kinds = f.inputs . iter() . map(|p| p.kind.clone()) . collect::<Vec<_>>();
+    let values = [Value::parse(&kinds[0], "(true, [a, b])").unwrap(), Value::parse(&kinds[1], "-1").unwrap()];
+    let call = [f.selector().to_vec(), abi::encode(&kinds, &values).unwrap()].concat();
+    let decoded = f.decode_input(&call).unwrap();
+    assert_eq!(serde_json::to_string(&decoded).unwrap(),
+      r#"{"name":"f","fields":{"0":[true,["a","b"]],"1":"-1"}}"#);
+    assert_ser_tokens(&decoded.values()[1].clone().compact(), &[Token::Bytes(&[0xff;32])]);
+  }
+}
@@ -2,7 +2,7 @@
 //------------------------------------------------------------------------------------------------//
 #[cfg(test)]
 mod signature {
-  use kawala::Signature;
+  use kawala::{ Signature, KawalaError };
 
 // ----------------------------------- CREATION AND ACCESS --------------------------------------//
 
@@ -53,5 +53,16 @@ mod signature {
     assert_eq!(sig.hex() . chars() . count(), 8);
     //... add a light show
   }
-}
 
+// ---------------------------------------- STD TRAITS ------------------------------------------//
+
+  #[test]
+  fn std_traits() {
+    let a : Signature = "a9059cbb".parse().unwrap();
+    assert_eq!(Signature::try_from(&[0xa9u8, 0x05, 0x9c, 0xbb][..]).unwrap(), a);
+    assert_eq!(Signature::from([0xa9, 0x05, 0x9c, 0xbb]), a.clone());
+    assert_eq!(a.as_ref(), [0xa9, 0x05, 0x9c, 0xbb]);
+    assert_eq!(Signature::try_from(&[1u8, 2][..]), Err(KawalaError::Length { expected : 4, found : 2 }));
+    assert!(Signature::from([0u8;4]) < a);
+  }
+}
@@ -0,0 +1,138 @@
+//----------------------------------------- TEMPLATE -------------------------------------------//
+// ----------------------------------------------------------------------------------------------//
+#[cfg(test)]
+mod template {
+  use kawala::{ Calldata, KawalaError, Template, Templates };
+  use kawala::abi::ParamType;
+  use std::collections::BTreeMap;
+
+  const USDC : &str = "0x2791bca1f2de4661ed88a30c99a7a9449aa84174";
+
+  const SET : &str = "
+    // the usual suspects
+    template transfer
+    selector transfer(address,uint256)
+    {recipient:address}
+    {amount:uint256}
+
+    template approve       # same shape, other selector
+    selector 095ea7b3
+    {spender:address} {amount:uint256}
+  ";
+
+  fn values(pairs : &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs . iter() . map(|(k, v)| (k.to_string(), v.to_string())) . collect()
+  }
+
+// ---------------------------------------- FILLING ---------------------------------------------//
+
+  #[test]
+  fn fill_transfer() {
+    let set    = Templates::from_text(SET).unwrap();
+    let filled = set.fill("transfer", &values(&[("recipient", USDC), ("amount", "1000")])).unwrap();
+    let expect = Calldata::builder()
+      . selector("transfer(address,uint256)") . address(USDC) . uint(1000) . build();
+    assert_eq!(filled, expect);
+    // a map of &str works as well
+    let approve = set.fill("approve", &BTreeMap::from([("spender", USDC), ("amount", "0x03e8")])).unwrap();
+    assert_eq!(approve.hex()[..8], *"095ea7b3");
+    assert_eq!(approve.hex()[8..], expect.hex()[8..]);
+  }
+
+  #[test]
+  fn list_holes() {
+    let set = Templates::from_text(SET).unwrap();
+    assert_eq!(set.names(), ["approve", "transfer"]);
+    assert_eq!(set.len(), 2);
+    let holes = set.get("transfer").unwrap().holes();
+    assert_eq!(holes, [
+      ("recipient", &ParamType::Address, false),
+      ("amount",    &ParamType::Uint(256), false)
+    ]);
+  }
+
+  #[test]
+  fn packed_and_repeated() {
+    let t = Template::parse("01 {to:address:packed} {n:uint16:packed} {to:address:packed}").unwrap();
+    assert_eq!(t.holes().len(), 2);
+    let filled = t.fill(&values(&[("to", USDC), ("n", "258")])).unwrap();
+    let expect = Calldata::builder()
+      . byte(0x01) . address_packed(USDC) . uint_packed(258, 2) . address_packed(USDC) . build();
+    assert_eq!(filled, expect);
+    let s = Template::parse("{memo:string:packed}").unwrap();
+    assert_eq!(s.fill(&values(&[("memo", "hi")])).unwrap().bytes(), b"hi");
+  }
+
+  // a selector line hashes the canonical signature, however it's spelled
+  #[test]
+  fn selector_spelling() {
+    let fill = |sig : &str| Template::parse(&format!("selector {}\n{{to:address}}", sig)).unwrap()
+      . fill(&values(&[("to", USDC)])).unwrap();
+    let expect = fill("transfer(address,uint256)");
+    assert_eq!(expect.bytes()[..4], [0xa9, 0x05, 0x9c, 0xbb]);
+    assert_eq!(fill("transfer(address, uint256)"), expect);
+    assert_eq!(fill("transfer(address,uint)"), expect);
+    assert_eq!(fill("transfer(address to, uint amount)"), expect);
+  }
+
+// ----------------------------------------- ERRORS ---------------------------------------------//
+
+  #[test]
+  fn fill_errors() {
+    let t = Template::parse("template transfer\nselector a9059cbb\n{to:address} {amount:uint8}").unwrap();
+    assert_eq!(t.name, "transfer");
+    assert_eq!(t.fill(&values(&[("to", USDC)])), Err(KawalaError::Missing("amount".to_owned())));
+    assert_eq!(
+      t.fill(&values(&[("to", USDC), ("amount", "256")])),
+      Err(KawalaError::Mistyped { name : "amount".to_owned(), kind : "uint8".to_owned(), value : "256".to_owned() })
+    );
+    assert!(matches!(t.fill(&values(&[("to", "0x12"), ("amount", "1")])), Err(KawalaError::Mistyped { .. })));
+    assert_eq!(
+      t.fill(&values(&[("to", USDC), ("amount", "1"), ("memo", "x")])),
+      Err(KawalaError::Unused("memo".to_owned()))
+    );
+    let set = Templates::new();
+    assert_eq!(set.fill("nope", &values(&[])), Err(KawalaError::Missing("nope".to_owned())));
+  }
+
+  #[test]
+  fn parse_errors() {
+    let err = |line : usize, text : &str| Err(KawalaError::Template { line, text : text.to_owned() });
+    assert_eq!(Template::parse("a9059cbb\n{to:addr}"),           err(2, "{to:addr}"));
+    assert_eq!(Template::parse("{to:address:loose}"),           err(1, "{to:address:loose}"));
+    assert_eq!(Template::parse("{data:bytes}"),                 err(1, "{data:bytes}"));
+    assert_eq!(Template::parse("{x:uint256} {x:address}"),     err(1, "{x:address}"));
+    assert_eq!(Template::parse("zz"),                           err(1, "zz"));
+    assert_eq!(Template::parse("selector transfer(adress,uint)"), err(1, "transfer(adress,uint)"));
+    assert_eq!(Template::parse("template a\ntemplate b"),       err(2, "template b"));
+    assert_eq!(Templates::from_text("00\ntemplate a"), Err(KawalaError::Template { line : 1, text : "00".to_owned() }));
+    let e = KawalaError::Template { line : 3, text : "zz".to_owned() };
+    assert_eq!(e.to_string(), "template line 3 can't be read at `zz`");
+  }
+
+// ---------------------------------------- STORAGE ---------------------------------------------//
+
+  #[test]
+  fn text_round_trip() {
+    let set  = Templates::from_text(SET).unwrap();
+    let back = Templates::from_text(&set.to_text()).unwrap();
+    assert_eq!(back, set);
+    let t = Template::parse("ff {a:bytes4:packed}").unwrap();
+    assert_eq!(Template::parse(&t.to_text()).unwrap(), t);
+  }
+
+  #[test]
+  #[cfg(feature = "std")]
+  fn save_and_load() {
+    let path = std::env::temp_dir().join(format!("kawala_templates_{}.txt", std::process::id()));
+    let mut set = Templates::from_text(SET).unwrap();
+    set.insert(Template::parse("template ping\n00000001").unwrap());
+    set.save(&path).unwrap();
+    let loaded = Templates::load(&path).unwrap();
+    std::fs::write(&path, "template bad\n{x:nothing}").unwrap();
+    let bad = Templates::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, set);
+    assert_eq!(bad.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+  }
+}
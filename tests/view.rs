@@ -1,8 +1,10 @@
 #[cfg(test)]
 mod view {
   
-  use kawala::{ View, Calldata, WithSig, Word };
+  use kawala::{ View, Calldata, WithSig, Word, EditMode, ViewConfig, KawalaError, Signature };
+  use kawala::abi::{ Value, AbiError };
   use kawala::bai;
+  use kawala::kwl32::util::pad32l;
 
   #[test]
   fn view() -> () {
@@ -205,6 +207,497 @@ mod view {
     assert_eq!(view.__word(0), expected);        
     assert_eq!(view.word_count(), 1);
   }
-}
 
+  // bind a named signature, slots by name
+  #[test]
+  fn bind_named_signature() {
+    let call = "0x38ed1739".to_owned()
+      + &"0".repeat(60) + "03e8"
+      + &"0".repeat(60) + "03e5"
+      + &"0".repeat(62) + "a0"
+      + &"0".repeat(24) + &"11".repeat(20)
+      + &"0".repeat(56) + "65211d05"
+      + &"0".repeat(63) + "1"
+      + &"0".repeat(24) + &"22".repeat(20);
+    let mut view = View::new(Calldata::from_hex(&call), WithSig::True);
+    assert!(view.decode().is_err());
+    view.bind_sig("function swapExactTokensForTokens(uint256 amountIn, uint256 amountOutMin, \
+      address[] path, address to, uint256 deadline) returns (uint256[])").unwrap();
+    assert_eq!(view.slot("amountOutMin"), Some(1));
+    assert_eq!(view.slot("deadline"),     Some(4));
+    assert_eq!(view.slot("nope"),         None);
+    assert_eq!(view.slot_name(2),         Some("path".to_owned()));
+    assert_eq!(view.decode().unwrap().to_string(),
+      "swapExactTokensForTokens(amountIn: 1000, amountOutMin: 997, \
+       path: [0x2222222222222222222222222222222222222222], \
+       to: 0x1111111111111111111111111111111111111111, deadline: 1696668933)");
+  }
+
+  // fill((address,uint256),address[],bytes) with nested and indexed paths
+  fn nested_view() -> View {
+    let call = "0x00000000".to_owned()
+      + &"0".repeat(24) + &"aa".repeat(20)            // order.maker
+      + &"0".repeat(60) + "0064"                      // order.amount
+      + &"0".repeat(62) + "80"                        // path offset
+      + &"0".repeat(62) + "e0"                        // data offset
+      + &"0".repeat(63) + "2"                         // path len
+      + &"0".repeat(24) + &"11".repeat(20)            // path[0]
+      + &"0".repeat(24) + &"22".repeat(20)            // path[1]
+      + &"0".repeat(64);                              // data len
+    let mut view = View::new(Calldata::from_hex(&call), WithSig::True);
+    view.bind_sig("fill((address maker, uint256 amount) order, address[] path, bytes data)").unwrap();
+    view
+  }
+
+  // get by name, nested and indexed
+  #[test]
+  fn get_named() {
+    let view = nested_view();
+    assert_eq!(view.get("order.maker").unwrap().to_string(), "0x".to_owned() + &"aa".repeat(20));
+    assert_eq!(view.get("order.amount").unwrap().to_string(), "100");
+    assert_eq!(view.get("path[1]").unwrap().to_string(), "0x".to_owned() + &"22".repeat(20));
+    assert_eq!(view.get("data").unwrap(), Value::Bytes(vec![]));
+    assert_eq!(view.get("path[2]"), Err(AbiError::OutOfBounds(128)));
+    assert!(view.get("order.taker").is_err());
+    assert!(View::new(Calldata::from_hex("00"), WithSig::False).get("x").is_err());
+  }
+
+  // set by name, type checked
+  #[test]
+  fn set_named() {
+    let mut view = nested_view();
+    view.set_str("order.amount", "0x2710").unwrap();
+    assert_eq!(view.get("order.amount").unwrap().to_string(), "10000");
+    view.set("path[0]", Value::Address([0x33;20])).unwrap();
+    assert_eq!(view.word(5), "0".repeat(24) + &"33".repeat(20));
+    // wrong type, and values the slot can't hold
+    assert!(matches!(view.set("path[0]", Value::Bool(true)), Err(AbiError::Type(_))));
+    assert!(matches!(view.set_str("order.maker", "12345"), Err(AbiError::Type(_))));
+    // dynamic values aren't a single word
+    assert!(matches!(view.set("data", Value::Bytes(vec![1])), Err(AbiError::Type(_))));
+    assert_eq!(view.word(5), "0".repeat(24) + &"33".repeat(20));
+  }
+
+  // clear by name
+  #[test]
+  fn clear_named() {
+    let mut view = nested_view();
+    view.clear_named("path[1]").unwrap();
+    assert_eq!(view.word(6), "0".repeat(64));
+    assert!(view.clear_named("order").is_err());
+    assert!(view.clear_named("path").is_err());
+  }
+
+  // structural edits in fixup mode keep offsets and lengths true
+  #[test]
+  fn fixup_bound() {
+    let mut view = nested_view();
+    view.set_edit_mode(EditMode::Fixup);
+    assert_eq!(view.remove(5), "0".repeat(24) + &"11".repeat(20));
+    assert_eq!(view.word(3), "0".repeat(62) + "c0");
+    assert_eq!(view.get("path").unwrap().to_string(), "[0x".to_owned() + &"22".repeat(20) + "]");
+    // appending lands at the end of the trailing bytes
+    view.append_from_bytes(&[0x44;32]);
+    assert_eq!(view.get("data").unwrap(), Value::Bytes(vec![0x44;32]));
+    assert_eq!(view.pop(), "44".repeat(32));
+    assert_eq!(view.get("data").unwrap(), Value::Bytes(vec![]));
+  }
 
+  // no abi bound, offsets and lengths are inferred
+  #[test]
+  fn fixup_inferred() {
+    let bound = nested_view();
+    let mut view = View::new(Calldata::from_bytes(&bound.bytes()), WithSig::True);
+    view.set_edit_mode(EditMode::Fixup);
+    view.remove(6);
+    view.bind(bound.function().unwrap().clone());
+    assert_eq!(view.get("path").unwrap().to_string(), "[0x".to_owned() + &"11".repeat(20) + "]");
+    assert_eq!(view.get("data").unwrap(), Value::Bytes(vec![]));
+  }
+
+  // raw mode leaves the words alone
+  #[test]
+  fn fixup_raw() {
+    let mut view = nested_view();
+    assert_eq!(view.edit_mode(), EditMode::Raw);
+    view.remove(5);
+    assert_eq!(view.word(3), "0".repeat(62) + "e0");
+    assert_eq!(view.word(4), "0".repeat(63) + "2");
+  }
+
+  // grow and shrink a dynamic array in place
+  #[test]
+  fn resize_array() {
+    let mut view = nested_view();
+    view.push_array_element("path", Value::Address([0x33;20])).unwrap();
+    assert_eq!(view.word_count(), 9);
+    assert_eq!(view.word(3), "0".repeat(61) + "100");
+    assert_eq!(view.get("path[2]").unwrap(), Value::Address([0x33;20]));
+    assert_eq!(view.get("data").unwrap(), Value::Bytes(vec![]));
+    assert_eq!(view.remove_array_element("path", 0).unwrap(), Value::Address([0x11;20]));
+    assert_eq!(view.get("path").unwrap(), Value::Array(vec![Value::Address([0x22;20]), Value::Address([0x33;20])]));
+    assert_eq!(view.get("order.amount").unwrap().to_string(), "100");
+    assert_eq!(view.word_count(), 8);
+    // wrong element type, index out of range, not an array
+    assert!(matches!(view.push_array_element("path", Value::Bool(true)), Err(AbiError::Type(_))));
+    assert_eq!(view.remove_array_element("path", 5), Err(AbiError::OutOfBounds(5)));
+    assert!(view.push_array_element("data", Value::Bool(true)).is_err());
+  }
+
+  // replace bytes with a payload of any length
+  #[test]
+  fn resize_bytes() {
+    let mut view = nested_view();
+    view.set_bytes("data", &[0xab;40]).unwrap();
+    assert_eq!(view.word_count(), 10);
+    assert_eq!(view.word(7), "0".repeat(62) + "28");
+    assert_eq!(view.word(9), "ab".repeat(8) + &"0".repeat(48));
+    assert_eq!(view.get("data").unwrap(), Value::Bytes(vec![0xab;40]));
+    // and a route change in front of it moves its offset
+    view.push_array_element("path", Value::Address([0x33;20])).unwrap();
+    assert_eq!(view.word(3), "0".repeat(61) + "100");
+    assert_eq!(view.get("data").unwrap(), Value::Bytes(vec![0xab;40]));
+    view.set_bytes("data", &[]).unwrap();
+    assert_eq!(view.word_count(), 9);
+    assert!(matches!(view.set_bytes("path", &[1]), Err(AbiError::Type(_))));
+  }
+
+  // an unpadded last value still resizes by whole words, and the result is
+  // chunked the way the view was configured
+  #[test]
+  fn resize_short_tail() {
+    let mut padded = nested_view();
+    padded.set_bytes("data", &[0xab;3]).unwrap();
+    let bytes = padded.bytes();
+    let mut short = View::new(Calldata::from_bytes(&bytes[..bytes.len() - 29]), WithSig::True);
+    short.bind_sig("fill((address maker, uint256 amount) order, address[] path, bytes data)").unwrap();
+    assert_eq!(short.get("data").unwrap(), Value::Bytes(vec![0xab;3]));
+    short.set_bytes("data", &[]).unwrap();
+    assert_eq!(short.bytes(), nested_view().bytes());
+    let mut strided = View::with_config(Calldata::from_bytes(&nested_view().bytes()), ViewConfig::new().stride(16));
+    strided.bind_sig("fill((address maker, uint256 amount) order, address[] path, bytes data)").unwrap();
+    strided.push_array_element("path", Value::Address([0x33;20])).unwrap();
+    let mut plain = nested_view();
+    plain.push_array_element("path", Value::Address([0x33;20])).unwrap();
+    assert_eq!(strided.bytes(), plain.bytes());
+    assert!(strided.iter() . all(|(_, _, w)| w.len() == 16));
+  }
+
+  // insert before an index, past the end appends
+  #[test]
+  fn insert_word() {
+    let mut view = View::new(Calldata::from_bytes(&[0u8;64]), WithSig::False);
+    view.insert(1, &"11".repeat(32));
+    view.insert_from_bytes(99, &[0x22;32]);
+    assert_eq!(view.page(), ["00".repeat(32), "11".repeat(32), "00".repeat(32), "22".repeat(32)]);
+    view.__insert(0, Word::from_bytes(&[0x33;32]));
+    assert_eq!(view.word(0), "33".repeat(32));
+    assert_eq!(view.word_count(), 5);
+  }
+
+  // pop from the front, empty pages give an empty word
+  #[test]
+  fn pop_front_word() {
+    let mut view = View::new(Calldata::from_bytes(&[[1u8;32], [2u8;32]].concat()), WithSig::False);
+    assert_eq!(view.pop_front(), "01".repeat(32));
+    assert_eq!(view.__pop_front(), Word::from_bytes(&[2u8;32]));
+    assert_eq!(view.pop_front(), "00".repeat(32));
+    assert_eq!(view.word_count(), 0);
+  }
+
+  // drain and splice ranges, trunc to max
+  #[test]
+  fn drain_and_splice() {
+    let bytes = (1..=4u8) . flat_map(|x| [x;32]) . collect::<Vec<u8>>();
+    let mut view = View::new(Calldata::from_bytes(&bytes), WithSig::False);
+    assert_eq!(view.drain(1..3), ["02".repeat(32), "03".repeat(32)]);
+    assert_eq!(view.page(), ["01".repeat(32), "04".repeat(32)]);
+    assert!(view.drain(5..9).is_empty());
+    let other = View::new(Calldata::from_bytes(&[[7u8;32], [8u8;32]].concat()), WithSig::False);
+    let removed = view.__splice(1..2, other.__page());
+    assert_eq!(removed, [Word::from_bytes(&[4u8;32])]);
+    assert_eq!(view.page(), ["01".repeat(32), "07".repeat(32), "08".repeat(32)]);
+    assert_eq!(view.splice(0..1, &[&"09".repeat(32)]), ["01".repeat(32)]);
+    assert_eq!(view.word(0), "09".repeat(32));
+    assert_eq!(view.splice(1..99, &[]).len(), 2);
+    assert_eq!(view.word_count(), 1);
+  }
+
+  // inserting inside an array in fixup mode grows it
+  #[test]
+  fn fixup_insert() {
+    let mut view = nested_view();
+    view.set_edit_mode(EditMode::Fixup);
+    view.insert_from_bytes(6, &pad32l(&[0x33;20]));
+    assert_eq!(view.get("path[1]").unwrap(), Value::Address([0x33;20]));
+    assert_eq!(view.get("path[2]").unwrap(), Value::Address([0x22;20]));
+    assert_eq!(view.get("data").unwrap(), Value::Bytes(vec![]));
+    view.drain(5..7);
+    assert_eq!(view.get("path").unwrap(), Value::Array(vec![Value::Address([0x22;20])]));
+    assert_eq!(view.get("data").unwrap(), Value::Bytes(vec![]));
+  }
+
+  // read and write across word boundaries
+  #[test]
+  fn byte_read_write() {
+    let mut view = View::new(Calldata::from_hex(&("0x12345678".to_owned() + &"00".repeat(96))), WithSig::True);
+    // a 20 byte address starting at byte 47 straddles words 1 and 2
+    view.write_bytes(47, &[0xaa;20]);
+    assert_eq!(view.read_bytes(47, 20), [0xaa;20]);
+    assert_eq!(view.word(1), "00".repeat(15) + &"aa".repeat(17));
+    assert_eq!(view.word(2), "aa".repeat(3) + &"00".repeat(29));
+    assert_eq!(view.sig(), "12345678");
+    // trunc at the end
+    view.write_bytes(94, &[0xbb;4]);
+    assert_eq!(view.read_bytes(90, 99), [0, 0, 0, 0, 0xbb, 0xbb]);
+    assert_eq!(view.word_count(), 3);
+    assert!(view.read_bytes(200, 4).is_empty());
+  }
+
+  // insert and delete, re-chunking what follows
+  #[test]
+  fn byte_insert_delete() {
+    let mut view = View::new(Calldata::from_bytes(&[[1u8;32], [2u8;32]].concat()), WithSig::False);
+    view.insert_bytes(30, &[0xff;4]);
+    assert_eq!(view.word_count(), 3);
+    assert_eq!(view.word(0), "01".repeat(30) + &"ff".repeat(2));
+    assert_eq!(view.word(1), "ff".repeat(2) + &"01".repeat(2) + &"02".repeat(28));
+    assert_eq!(view.word(2), "02".repeat(4));
+    view.delete_bytes(30, 4);
+    assert_eq!(view.page(), ["01".repeat(32), "02".repeat(32)]);
+    // appending to a short last word fills it first
+    view.delete_bytes(60, 99);
+    view.insert_bytes(60, &[3u8;8]);
+    assert_eq!(view.page(), ["01".repeat(32), "02".repeat(28) + &"03".repeat(4), "03".repeat(4)]);
+  }
+
+  // whole words on a boundary are word edits, so fixup follows them
+  #[test]
+  fn byte_fixup() {
+    let mut view = nested_view();
+    view.set_edit_mode(EditMode::Fixup);
+    view.insert_bytes(224, &pad32l(&[0x33;20]));
+    assert_eq!(view.get("path[2]").unwrap(), Value::Address([0x33;20]));
+    assert_eq!(view.get("data").unwrap(), Value::Bytes(vec![]));
+    view.delete_bytes(160, 32);
+    assert_eq!(view.get("path").unwrap(), Value::Array(vec![Value::Address([0x22;20]), Value::Address([0x33;20])]));
+    assert_eq!(view.get("data").unwrap(), Value::Bytes(vec![]));
+  }
+
+  // a command byte header over 52 byte records, 20 + 32
+  #[test]
+  fn config_strides() {
+    let record = "aa".repeat(20) + &"bb".repeat(32);
+    let stream = "01".to_owned() + &record + &record;
+    let config = ViewConfig::new().header(1).strides(&[20, 32]);
+    let mut view = View::with_config(Calldata::from_hex(&stream), config);
+    assert_eq!(view.sig(), "01");
+    assert_eq!(view.page(), ["aa".repeat(20), "bb".repeat(32), "aa".repeat(20), "bb".repeat(32)]);
+    assert_eq!(view.calldata(), stream);
+    view.replace(2, &"cc".repeat(20));
+    assert_eq!(view.read_bytes(52, 20), [0xcc;20]);
+    view.replace_header(&[0x02]);
+    assert_eq!(view.sig(), "02");
+    // an inserted record re-chunks on the record boundaries
+    view.insert_bytes(52, &[0xdd;52]);
+    assert_eq!(view.word(2), "dd".repeat(20));
+    assert_eq!(view.word(3), "dd".repeat(32));
+    assert_eq!(view.word(4), "cc".repeat(20));
+    assert_eq!(view.word_count(), 6);
+  }
+
+  // an 8 byte discriminator, and a region with its own stride
+  #[test]
+  fn config_header_and_regions() {
+    let data = "11".repeat(8) + &"22".repeat(32);
+    let view = View::with_config(Calldata::from_hex(&data), ViewConfig::new().header(8));
+    assert_eq!(view.sig(), "11".repeat(8));
+    assert_eq!(view.page(), ["22".repeat(32)]);
+    assert_eq!(view.config().header_len(), 8);
+    let data = "01020304".to_owned() + &"33".repeat(40);
+    let config = ViewConfig::new().header(0).region(4, 1).stride(20);
+    let view = View::with_config(Calldata::from_hex(&data), config);
+    assert_eq!(view.page(), ["01", "02", "03", "04", &"33".repeat(20), &"33".repeat(20)]);
+    // a record over a word is cut into words and what's left, `WithSig` maps onto a config
+    let view = View::with_config(Calldata::from_hex(&"44".repeat(104)), ViewConfig::new().header(0).stride(52));
+    assert_eq!(view.page(), ["44".repeat(32), "44".repeat(20), "44".repeat(32), "44".repeat(20)]);
+    assert_eq!(ViewConfig::from(WithSig::False), ViewConfig::new().header(0));
+    let a = View::new(Calldata::from_hex(&data), WithSig::True);
+    let b = View::with_config(Calldata::from_hex(&data), ViewConfig::default());
+    assert_eq!((a.sig(), a.page()), (b.sig(), b.page()));
+  }
+
+  // named and structural edits on a configured page go by bytes, not words
+  #[test]
+  fn config_named_edits() {
+    let bound = nested_view();
+    let call  = [&[0xee;8][..], &bound.data_bytes()].concat();
+    // an 8 byte header and 16 byte chunks, a word's value spans two
+    let mut view = View::with_config(Calldata::from_bytes(&call), ViewConfig::new().header(8).stride(16));
+    view.bind(bound.function().unwrap().clone());
+    view.set_edit_mode(EditMode::Fixup);
+    assert_eq!(view.word_count(), 16);
+    assert_eq!((view.slot("path"), view.slot_name(6)), (Some(4), Some("data".to_owned())));
+    view.set("path[0]", Value::Address([0x33;20])).unwrap();
+    assert_eq!(view.word(10), "00".repeat(12) + &"33".repeat(4));
+    assert_eq!(view.word(11), "33".repeat(16));
+    assert_eq!(view.get("path[0]").unwrap(), Value::Address([0x33;20]));
+    view.clear_named("order.amount").unwrap();
+    assert_eq!(view.get("order.amount").unwrap().to_string(), "0");
+    // grown and shrunk, the page stays in 16 byte chunks
+    view.push_array_element("path", Value::Address([0x44;20])).unwrap();
+    assert_eq!(view.word_count(), 18);
+    assert_eq!(view.get("path[2]").unwrap(), Value::Address([0x44;20]));
+    assert_eq!(view.get("data").unwrap(), Value::Bytes(vec![]));
+    assert_eq!(view.remove_array_element("path", 0).unwrap(), Value::Address([0x33;20]));
+    assert_eq!(view.get("path").unwrap(), Value::Array(vec![Value::Address([0x22;20]), Value::Address([0x44;20])]));
+    assert!(view.iter() . all(|(_, _, w)| w.len() == 16));
+    // whole words in and out at a word boundary still fix the links up
+    view.insert_bytes(192, &pad32l(&[0x55;20]));
+    assert_eq!(view.get("path[1]").unwrap(), Value::Address([0x55;20]));
+    assert_eq!(view.get("data").unwrap(), Value::Bytes(vec![]));
+    view.delete_bytes(160, 32);
+    assert_eq!(view.get("path").unwrap(), Value::Array(vec![Value::Address([0x55;20]), Value::Address([0x44;20])]));
+    view.append_from_bytes(&[0x66;32]);
+    assert_eq!(view.get("data").unwrap(), Value::Bytes(vec![0x66;32]));
+    view.pop();
+    assert_eq!(view.get("data").unwrap(), Value::Bytes(vec![]));
+    assert_eq!(view.sig(), "ee".repeat(8));
+  }
+
+  // the checked replace_with is the plain one plus bounds errors
+  #[test]
+  fn try_replace_with_parity() {
+    let page = (1..=4u8) . map(|i| [i;32]) . collect::<Vec<[u8;32]>>() . concat();
+    for (to, from) in (0..4) . flat_map(|to| (0..4) . map(move |from| (to, from))) {
+      let mut plain = View::new(Calldata::from_bytes(&page), WithSig::False);
+      let mut tried = plain.clone();
+      plain.replace_with(to, from);
+      tried.try_replace_with(to, from).unwrap();
+      assert_eq!(plain.page(), tried.page(), "replace_with({}, {})", to, from);
+    }
+    let mut view = View::new(Calldata::from_bytes(&page), WithSig::False);
+    view.replace_with(2, 0);
+    assert_eq!(view.page(), ["02".repeat(32), "03".repeat(32), "01".repeat(32)]);
+    assert_eq!(view.try_replace_with(3, 0), Err(KawalaError::OutOfBounds { index : 3, len : 3 }));
+    assert_eq!(view.try_replace_with(0, 3), Err(KawalaError::OutOfBounds { index : 3, len : 3 }));
+    assert_eq!(view.word_count(), 3);
+  }
+
+  // an empty page no longer panics on the clamping methods
+  #[test]
+  fn empty_page_clamps() {
+    let mut view = View::new(Calldata::from_hex("0x12345678"), WithSig::True);
+    assert_eq!(view.word_count(), 0);
+    assert_eq!(view.data(), "");
+    assert_eq!(view.word(3), "00".repeat(32));
+    assert_eq!(view.remove(0), "00".repeat(32));
+    view.replace(0, "ff"); view.clear(0); view.left_pad(0); view.right_shift(0, 1);
+    view.replace_with(0, 1); view.xor_into(0, &[1]);
+    assert_eq!(view.word_count(), 0);
+    // short and odd hex
+    assert_eq!(Word::from_hex("0").bytes(), [0u8;0]);
+    assert_eq!(Calldata::from_hex("").len(), 0);
+    assert_eq!(Signature::from_hex("0x").bytes(), [0u8;4]);
+  }
+
+  // try_ variants are strict
+  #[test]
+  fn try_checked() {
+    let mut view = View::new(Calldata::from_bytes(&[[1u8;32], [2u8;32]].concat()), WithSig::False);
+    assert_eq!(view.try_word(1).unwrap(), "02".repeat(32));
+    assert_eq!(view.try_word(2), Err(KawalaError::OutOfBounds { index : 2, len : 2 }));
+    assert_eq!(view.try_replace(5, "ff"), Err(KawalaError::OutOfBounds { index : 5, len : 2 }));
+    assert_eq!(view.try_replace(0, "fg"), Err(KawalaError::Hex("fg".to_owned())));
+    assert_eq!(view.try_replace_from_bytes(0, &[0u8;33]), Err(KawalaError::TooLong { max : 32, found : 33 }));
+    assert_eq!(view.try_replace_sig("0x1234"), Err(KawalaError::Length { expected : 4, found : 2 }));
+    assert_eq!(view.try_replace_sig_from_bytes(&[1, 2, 3, 4, 5]), Err(KawalaError::Length { expected : 4, found : 5 }));
+    assert_eq!(view.try_append_from_bytes(&[0u8;33]), Err(KawalaError::TooLong { max : 32, found : 33 }));
+    assert_eq!(view.try_words(1, 0), Err(KawalaError::BadRange { start : 1, end : 0 }));
+    assert_eq!(view.try_read_bytes(60, 5), Err(KawalaError::ByteOutOfBounds { offset : 65, len : 64 }));
+    // nothing was touched
+    assert_eq!(view.page(), ["01".repeat(32), "02".repeat(32)]);
+    view.try_append_from_bytes(&[3u8;32]).unwrap();
+    view.try_replace_sig_from_bytes(&[0xa9, 0x05, 0x9c, 0xbb]).unwrap();
+    assert_eq!((view.word(2), view.sig()), ("03".repeat(32), "a9059cbb".to_owned()));
+    assert_eq!(view.try_pop().unwrap(), "03".repeat(32));
+    view.try_insert(2, &"03".repeat(32)).unwrap();
+    view.try_replace_with(0, 2).unwrap();
+    assert_eq!(view.page(), ["03".repeat(32), "02".repeat(32)]);
+    assert_eq!(view.try_drain(0..2).unwrap().len(), 2);
+    assert_eq!(view.try_pop(), Err(KawalaError::Empty));
+    assert_eq!(view.try_pop_front(), Err(KawalaError::Empty));
+    assert_eq!(view.try_replace_with(0, 0), Err(KawalaError::OutOfBounds { index : 0, len : 0 }));
+    assert_eq!(KawalaError::OutOfBounds { index : 2, len : 0 }.to_string(), "word 2 out of bounds, page has 0");
+    let abi : KawalaError = kawala::abi::AbiError::OutOfBounds(64).into();
+    assert_eq!(abi.to_string(), "read out of bounds at byte 64");
+  }
+
+  // std traits, eq by sig and words
+  #[test]
+  fn std_traits() {
+    let hex = "0xa9059cbb".to_owned() + &"11".repeat(32) + &"22".repeat(32);
+    let mut view : View = hex.parse().unwrap();
+    let copy = view.clone();
+    assert_eq!(view, copy);
+    assert_eq!(view[1], Word::from([0x22;32]));
+    view[1] = Word::from([0x33;32]);
+    assert_ne!(view, copy);
+    assert!(copy < view);
+    assert_eq!(View::try_from(&[1u8, 2][..]), Err(KawalaError::Length { expected : 4, found : 2 }));
+    // iterate, extend and collect
+    assert_eq!((&view) . into_iter() . count(), 2);
+    (&mut view) . into_iter() . for_each(|w| *w = Word::from([0u8;32]));
+    view.extend([Word::from([0x44;32])]);
+    assert_eq!(view.word_count(), 3);
+    let words = view . into_iter() . collect::<Vec<Word>>();
+    let built = words . into_iter() . collect::<View>();
+    assert_eq!(built.sig(), "");
+    assert_eq!(built.word(2), "44".repeat(32));
+    let mut set = std::collections::HashSet::new();
+    set.insert(copy.clone()); set.insert(copy);
+    assert_eq!(set.len(), 1);
+  }
+
+  // trailing zero padding is different calldata, so a different view
+  #[test]
+  fn eq_by_bytes() {
+    let short  : View = "0xa9059cbb01".parse().unwrap();
+    let padded : View = ("0xa9059cbb01".to_owned() + &"00".repeat(31)).parse().unwrap();
+    assert_eq!(short[0], padded[0]);
+    assert_ne!(short, padded);
+    assert_ne!(short.cmp(&padded), std::cmp::Ordering::Equal);
+    assert!(short < padded);
+    let set = [short.clone(), padded, short] . into_iter() . collect::<std::collections::HashSet<View>>();
+    assert_eq!(set.len(), 2);
+  }
+
+  // walk words with their index and byte offset
+  #[test]
+  fn iterate_words() {
+    let config = ViewConfig::new().header(1).strides(&[20, 32]);
+    let stream = "01".to_owned() + &"aa".repeat(20) + &"00".repeat(32) + &"bb".repeat(20);
+    let mut view = View::with_config(Calldata::from_hex(&stream), config);
+    let walked = view.iter() . map(|(i, o, w)| (i, o, w.len())) . collect::<Vec<_>>();
+    assert_eq!(walked, [(0, 0, 20), (1, 20, 32), (2, 52, 20)]);
+    assert_eq!(view.nonzero() . map(|(i, _, _)| i) . collect::<Vec<_>>(), [0, 2]);
+    assert_eq!(view.find(|w| w.bytes()[0] == 0xbb) . map(|(i, o, _)| (i, o)), Some((2, 52)));
+    assert!(view.find(|w| w.len() == 1).is_none());
+    // many words changed in place
+    view.iter_mut() . filter(|(_, _, w)| !w.is_zero()) . for_each(|(_, _, w)| *w = Word::from_bytes(&[0xcc;20]));
+    assert_eq!(view.page(), ["cc".repeat(20), "00".repeat(32), "cc".repeat(20)]);
+  }
+
+  // windows and chunks of words
+  #[test]
+  fn windows_and_chunks() {
+    let bytes = (1..=5u8) . flat_map(|x| [x;32]) . collect::<Vec<u8>>();
+    let view = View::new(Calldata::from_bytes(&bytes), WithSig::False);
+    let windows = view.windows(2) . map(|(i, o, w)| (i, o, w.len())) . collect::<Vec<_>>();
+    assert_eq!(windows, [(0, 0, 2), (1, 32, 2), (2, 64, 2), (3, 96, 2)]);
+    let chunks = view.chunks_of(2) . map(|(i, o, w)| (i, o, w.len())) . collect::<Vec<_>>();
+    assert_eq!(chunks, [(0, 0, 2), (2, 64, 2), (4, 128, 1)]);
+    assert_eq!(view.windows(9).count(), 0);
+    assert_eq!(view.chunks_of(0).count(), 5);
+  }
+}
@@ -2,7 +2,8 @@
 // ----------------------------------------------------------------------------------------------//
 #[cfg(test)]
 mod word { 
-  use kawala::{ Word };
+  use kawala::{ Word, Bytes, KawalaError };
+  use std::collections::{ BTreeSet, HashSet };
 // ----------------------------------- CREATION AND ACCESS --------------------------------------//
 
   #[test]
@@ -78,5 +79,56 @@ mod word {
     assert_eq!(bytes,    [0u8;32]);
     assert_eq!(len,       32);
   }
-}
 
+// ---------------------------------------- STD TRAITS ------------------------------------------//
+
+  #[test]
+  fn std_traits() {
+    let a : Word = "0x01".parse().unwrap();
+    let b = Word::try_from(&[0x02u8][..]).unwrap();
+    assert_eq!(a.clone(), a);
+    assert!(a < b);
+    assert_eq!(Word::from([7u8;32]), Word::from_bytes(&[7u8;32]));
+    assert_eq!(Word::from([7u8;32]).as_ref(), [7u8;32]);
+    assert_eq!("xyz".parse::<Word>(), Err(KawalaError::Hex("xyz".to_owned())));
+    assert!(Word::try_from(&[0u8;33][..]).is_err());
+    let set = [a, b, a] . into_iter() . collect::<HashSet<Word>>();
+    assert_eq!(set.len(), 2);
+    let ord = [b, a] . into_iter() . collect::<BTreeSet<Word>>();
+    assert_eq!(ord . into_iter() . collect::<Vec<_>>(), [a, b]);
+  }
+
+// ---------------------------------- STORAGE AND EQUALITY --------------------------------------//
+
+  #[test]
+  fn inline_storage() {
+    // 32 bytes and a length, nothing on the heap
+    assert_eq!(std::mem::size_of::<Word>(), 33);
+    let a = Word::from_bytes(&[1, 2]);
+    let b = a;
+    assert_eq!(a, b);
+    assert_eq!(a.as_bytes32()[..3], [1, 2, 0]);
+    assert_eq!(a.data(), Bytes::Array(vec![1, 2]));
+    assert!(matches!(Word::from([1u8;32]).data(), Bytes::Bytes32(_)));
+  }
+
+  #[test]
+  fn equality_by_content() {
+    // however it was built, same bytes are the same word
+    let full = Word::from([9u8;32]);
+    assert_eq!(full, Word::from_bytes(&[9u8;40]));
+    assert_eq!(full, Word::from_hex(&"09".repeat(32)));
+    assert_eq!(Word::from_bytes(&[]), Word::from_hex(""));
+    // a short word is its padded self, its bytes still aren't
+    let short  = Word::from_bytes(&[0xab]);
+    let padded = Word::from_bytes(&short.as_bytes32());
+    assert_eq!(short, padded);
+    assert_ne!(short.bytes(), padded.bytes());
+    assert_ne!(short, Word::from_bytes(&[0xac]));
+    // order follows the padded bytes
+    assert_eq!(short.cmp(&padded), core::cmp::Ordering::Equal);
+    assert!(Word::from_bytes(&[0xab, 0x01]) > padded && Word::from_bytes(&[0xaa; 32]) < short);
+    let set = [short, padded, Word::from_hex("ab"), Word::from_hex("ac")] . into_iter() . collect::<HashSet<Word>>();
+    assert_eq!(set.len(), 2);
+  }
+}